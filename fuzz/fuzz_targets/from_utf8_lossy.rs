@@ -0,0 +1,22 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use yangon::{yPolicy, Yangon};
+use yangon_fuzz::{check, CAP};
+
+fuzz_target!(|data: &[u8]| {
+    let lossy = Yangon::<CAP>::from_utf8_lossy(data);
+    assert!(lossy.len() <= CAP || std::str::from_utf8(data).is_ok());
+    let expected = String::from_utf8_lossy(data);
+    assert!(expected.starts_with(&*lossy));
+    for policy in [
+        yPolicy::ReplaceChar('\u{FFFD}'),
+        yPolicy::ReplaceStr("<?>"),
+        yPolicy::Skip,
+        yPolicy::Escape,
+        yPolicy::Stop,
+    ] {
+        let (y, report) = Yangon::<CAP>::from_utf8_with(data, policy);
+        check(&y);
+        assert!(report.truncated_bytes <= data.len());
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use yangon_fuzz::{run, Op};
+
+fuzz_target!(|input: (String, Vec<Op>)| {
+    run(&input.0, &input.1, |op| {
+        matches!(op, Op::Replace(..) | Op::ReplaceChar(..) | Op::Push(_) | Op::PushStr(_) | Op::Pop)
+    });
+});
@@ -0,0 +1,123 @@
+use arbitrary::Arbitrary;
+use yangon::Yangon;
+
+
+pub const CAP: usize = 64;
+
+
+#[derive(Arbitrary, Debug)]
+pub enum Op {
+    Push(char),
+    PushStr(String),
+    Pop,
+    Insert(usize, char),
+    Remove(usize),
+    Replace(String, String),
+    ReplaceChar(char, String),
+    ReplaceRange(usize, usize, String),
+    Retain(u8),
+    SplitOff(usize),
+    ShrinkTo(usize),
+}
+
+
+pub fn check<const N: usize>(y: &Yangon<N>) {
+    assert!(y.len() <= y.capacity(), "len {} > capacity {}", y.len(), y.capacity());
+    assert!(y.capacity() <= N, "capacity {} > N {}", y.capacity(), N);
+    assert!(std::str::from_utf8(y.as_bytes()).is_ok(), "invalid UTF-8: {:?}", y.as_bytes());
+}
+
+
+fn boundary(s: &str, sel: usize) -> usize {
+    let count: usize = s.chars().count() + 1;
+    s.char_indices().map(|(i, _)| i).chain([s.len()]).nth(sel % count).unwrap()
+}
+
+
+fn keep(sel: u8) -> fn(char) -> bool {
+    match sel % 4 {
+        0 => |c| c.is_ascii(),
+        1 => |c| c != '\0',
+        2 => |c| c.is_alphanumeric(),
+        _ => |c| c.len_utf8() < 3,
+    }
+}
+
+
+pub fn apply<const N: usize>(y: &mut Yangon<N>, op: &Op) {
+    let room: usize = y.capacity() - y.len();
+    match op {
+        Op::Push(ch) => assert_eq!(y.push(*ch).is_ok(), ch.len_utf8() <= room),
+        Op::PushStr(slice) => assert_eq!(y.push_str(slice).is_ok(), slice.len() <= room),
+        Op::Pop => {
+            let expected: Option<char> = y.as_str().chars().last();
+            assert_eq!(y.pop(), expected);
+        }
+        Op::Insert(idx, ch) => {
+            if ch.len_utf8() <= room {
+                let idx: usize = boundary(y.as_str(), *idx);
+                y.insert(idx, *ch);
+                assert_eq!(y.as_str()[idx..].chars().next(), Some(*ch));
+            }
+        }
+        Op::Remove(idx) => {
+            if !y.is_empty() {
+                let idx: usize = boundary(y.as_str(), *idx);
+                let idx: usize = if idx == y.len() { 0 } else { idx };
+                let expected: Option<char> = y.as_str()[idx..].chars().next();
+                assert_eq!(Some(y.remove(idx)), expected);
+            }
+        }
+        Op::Replace(from, to) => {
+            let expected: String = y.as_str().replace(from.as_str(), to);
+            if expected.len() <= N {
+                *y = y.replace::<&str, 0>(from, to);
+                assert_eq!(y.as_str(), expected);
+            }
+        }
+        Op::ReplaceChar(from, to) => {
+            let expected: String = y.as_str().replace(*from, to);
+            if expected.len() <= N {
+                *y = y.replace::<char, 0>(*from, to);
+                assert_eq!(y.as_str(), expected);
+            }
+        }
+        Op::ReplaceRange(a, b, slice) => {
+            let (a, b): (usize, usize) = (boundary(y.as_str(), *a), boundary(y.as_str(), *b));
+            let (a, b): (usize, usize) = if a <= b { (a, b) } else { (b, a) };
+            if y.len() - (b - a) + slice.len() <= y.capacity() {
+                let mut expected: String = y.to_string();
+                expected.replace_range(a..b, slice);
+                y.replace_range(a..b, slice);
+                assert_eq!(y.as_str(), expected);
+            }
+        }
+        Op::Retain(sel) => {
+            let mut expected: String = y.to_string();
+            expected.retain(keep(*sel));
+            y.retain(keep(*sel));
+            assert_eq!(y.as_str(), expected);
+        }
+        Op::SplitOff(idx) => {
+            let idx: usize = boundary(y.as_str(), *idx);
+            let expected: String = y.as_str()[idx..].to_string();
+            let tail: Yangon<N> = y.split_off(idx);
+            check(&tail);
+            assert_eq!(tail.as_str(), expected);
+        }
+        Op::ShrinkTo(cap) => y.shrink_to(*cap),
+    }
+    check(y);
+}
+
+
+pub fn run(seed: &str, ops: &[Op], allow: fn(&Op) -> bool) {
+    let mut y: Yangon<CAP> = Yangon::with_capacity();
+    if y.push_str(seed).is_err() {
+        return;
+    }
+    check(&y);
+    for op in ops.iter().filter(|&op| allow(op)) {
+        apply(&mut y, op);
+    }
+}
@@ -0,0 +1,46 @@
+#ifndef YANGON_H
+#define YANGON_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define YANGON_CAPACITY 256
+#define YANGON_STORAGE_SIZE (YANGON_CAPACITY + 2 * sizeof(size_t))
+
+/* Mirrors yError. */
+#define YANGON_OK 0
+#define YANGON_FROM_UTF8_ERROR 1
+#define YANGON_FROM_UTF16_ERROR 2
+#define YANGON_ENCODING_ERROR 3
+#define YANGON_NUL_ERROR 4
+#define YANGON_CAPACITY_OVERFLOW 5
+#define YANGON_TOO_MANY_FIELDS 6
+#define YANGON_FIELD_TOO_LONG 7
+#define YANGON_NULL_POINTER (-1)
+
+/* Opaque Yangon<256>. Allocate it anywhere (stack, static, struct field) and call yangon_init. */
+typedef struct yangon {
+    union {
+        unsigned char bytes[YANGON_STORAGE_SIZE];
+        size_t align;
+    } storage;
+} yangon_t;
+
+size_t yangon_storage_size(void);
+int yangon_init(yangon_t *y);
+int yangon_push_str(yangon_t *y, const char *s, size_t len);
+size_t yangon_len(const yangon_t *y);
+size_t yangon_capacity(const yangon_t *y);
+/* Not NUL-terminated; pair it with yangon_len. */
+const char *yangon_as_ptr(const yangon_t *y);
+void yangon_clear(yangon_t *y);
+int yangon_replace(yangon_t *y, const char *from, size_t from_len, const char *to, size_t to_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,175 @@
+use crate::{yError, Yangon};
+use std::str::{self, from_utf8_unchecked};
+
+
+pub struct Utf8Appender<'a, const N: usize> {
+    target: &'a mut Yangon<N>,
+    pending: [u8; 4],
+    pending_len: usize,
+    lossy: bool,
+}
+
+
+impl<'a, const N: usize> Utf8Appender<'a, N> {
+    
+    pub fn new(target: &'a mut Yangon<N>) -> Self {
+        Self {
+            target,
+            pending: [0, 0, 0, 0],
+            pending_len: 0,
+            lossy: false,
+        }
+    }
+
+    
+    pub fn lossy(target: &'a mut Yangon<N>) -> Self {
+        Self {
+            target,
+            pending: [0, 0, 0, 0],
+            pending_len: 0,
+            lossy: true,
+        }
+    }
+
+    
+    #[inline]
+    pub fn pending(self: &Self) -> &[u8] {
+        &(*self).pending[..(*self).pending_len]
+    }
+
+    
+    pub(crate) fn room(self: &Self) -> usize {
+        let used: usize = (*self).target.len() + (*self).pending_len;
+        (*self).target.capacity().saturating_sub(used)
+    }
+
+    
+    pub fn push_bytes(self: &mut Self, chunk: &[u8]) -> Result<(), yError> {
+        let byt_len: usize = (*self).process(chunk, false)?;
+        if (*self).target.len() + byt_len > (*self).target.capacity() {
+            return Err(yError::CapacityOverflow);
+        }
+        (*self).process(chunk, true)?;
+        Ok(())
+    }
+
+    
+    pub(crate) fn push_prefix(self: &mut Self, chunk: &[u8]) -> Result<usize, yError> {
+        match (*self).push_bytes(chunk) {
+            Ok(()) => return Ok(chunk.len()),
+            Err(yError::CapacityOverflow) => {}
+            Err(e) => return Err(e),
+        }
+        let mut byt_len: usize = (*self).room();
+        if byt_len > chunk.len() {
+            byt_len = chunk.len();
+        }
+        while byt_len > 0 {
+            match (*self).push_bytes(&chunk[..byt_len]) {
+                Ok(()) => return Ok(byt_len),
+                Err(yError::CapacityOverflow) => byt_len /= 2,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(0)
+    }
+
+    
+    pub fn finish(self: Self) -> Result<(), yError> {
+        if self.pending_len == 0 {
+            Ok(())
+        } else if self.lossy {
+            self.target.push('\u{FFFD}')
+        } else {
+            Err(yError::FromUtf8Error)
+        }
+    }
+
+    
+    fn emit(self: &mut Self, slice: &str, commit: bool) -> usize {
+        if commit {
+            unsafe {
+                (*self).target.push_str_unchecked(slice);
+            }
+        }
+        slice.len()
+    }
+
+    
+    fn invalid(self: &mut Self, commit: bool) -> Result<usize, yError> {
+        if (*self).lossy {
+            Ok((*self).emit("\u{FFFD}", commit))
+        } else {
+            Err(yError::FromUtf8Error)
+        }
+    }
+
+    
+    fn process(self: &mut Self, chunk: &[u8], commit: bool) -> Result<usize, yError> {
+        let mut byt_len: usize = 0;
+        let mut srt_idx: usize = 0;
+        let pending_len: usize = (*self).pending_len;
+        if pending_len > 0 {
+            let mut bind: [u8; 4] = (*self).pending;
+            let mut bind_len: usize = pending_len;
+            while bind_len < 4 && srt_idx < chunk.len() {
+                bind[bind_len] = chunk[srt_idx];
+                bind_len += 1;
+                srt_idx += 1;
+                match str::from_utf8(&bind[..bind_len]) {
+                    Err(e) if e.error_len().is_none() => continue,
+                    _ => break,
+                }
+            }
+            match str::from_utf8(&bind[..bind_len]) {
+                Ok(slice) => {
+                    byt_len += (*self).emit(slice, commit);
+                }
+                Err(e) => match e.error_len() {
+                    None => {
+                        if commit {
+                            (*self).pending = bind;
+                            (*self).pending_len = bind_len;
+                        }
+                        return Ok(byt_len);
+                    }
+                    Some(err_len) => {
+                        byt_len += (*self).invalid(commit)?;
+                        srt_idx = err_len - pending_len;
+                    }
+                },
+            }
+        }
+        loop {
+            match str::from_utf8(&chunk[srt_idx..]) {
+                Ok(slice) => {
+                    byt_len += (*self).emit(slice, commit);
+                    if commit {
+                        (*self).pending_len = 0;
+                    }
+                    return Ok(byt_len);
+                }
+                Err(e) => {
+                    let valid: &str =
+                        unsafe { from_utf8_unchecked(&chunk[srt_idx..srt_idx + e.valid_up_to()]) };
+                    byt_len += (*self).emit(valid, commit);
+                    srt_idx += e.valid_up_to();
+                    match e.error_len() {
+                        Some(err_len) => {
+                            byt_len += (*self).invalid(commit)?;
+                            srt_idx += err_len;
+                        }
+                        None => {
+                            if commit {
+                                let tail: &[u8] = &chunk[srt_idx..];
+                                (*self).pending[..tail.len()].copy_from_slice(tail);
+                                (*self).pending_len = tail.len();
+                            }
+                            return Ok(byt_len);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Yangon;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+
+fn fitting<const N: usize>(slice: &str) -> Yangon<N> {
+    let mut end_idx: usize = if slice.len() < N { slice.len() } else { N };
+    while !slice.is_char_boundary(end_idx) {
+        end_idx -= 1;
+    }
+    Yangon::from(&slice[..end_idx])
+}
+
+
+impl<'a, const N: usize> Arbitrary<'a> for Yangon<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(fitting(<&str>::arbitrary(u)?))
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Ok(fitting(<&str>::arbitrary_take_rest(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str as Arbitrary<'a>>::size_hint(depth)
+    }
+}
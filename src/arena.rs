@@ -0,0 +1,198 @@
+use crate::yError;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem::MaybeUninit,
+    ops::Index,
+    slice::{self, from_raw_parts},
+    str::from_utf8_unchecked,
+};
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ArenaHandle {
+    offset: u32,
+    len: u32,
+}
+
+
+impl ArenaHandle {
+    
+    #[inline]
+    pub fn offset(self: &Self) -> usize {
+        (*self).offset as usize
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len as usize
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+}
+
+
+#[derive(Clone)]
+pub struct YangonArena<const BYTES: usize, const COUNT: usize> {
+    list: [MaybeUninit<u8>; BYTES],
+    len: usize,
+    handles: [ArenaHandle; COUNT],
+    count: usize,
+}
+
+
+impl<const BYTES: usize, const COUNT: usize> YangonArena<BYTES, COUNT> {
+    
+    const FITS_U32: () = assert!(BYTES <= u32::MAX as usize, "YangonArena offsets are u32");
+
+    
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::FITS_U32;
+        Self {
+            list: [MaybeUninit::uninit(); BYTES],
+            len: 0,
+            handles: [ArenaHandle { offset: 0, len: 0 }; COUNT],
+            count: 0,
+        }
+    }
+
+    
+    pub fn insert(self: &mut Self, slice: &str) -> Result<ArenaHandle, yError> {
+        let len: usize = (*self).len;
+        if (*self).count == COUNT || slice.len() > BYTES - len {
+            return Err(yError::CapacityOverflow);
+        }
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
+        for (idx, &x) in slice.as_bytes().iter().enumerate() {
+            unsafe {
+                *ptr.add(len + idx) = x;
+            }
+        }
+        let handle: ArenaHandle = ArenaHandle {
+            offset: len as u32,
+            len: slice.len() as u32,
+        };
+        (*self).handles[(*self).count] = handle;
+        (*self).count += 1;
+        (*self).len += slice.len();
+        Ok(handle)
+    }
+
+    
+    fn as_str(self: &Self) -> &str {
+        unsafe { from_utf8_unchecked(from_raw_parts((*self).list.as_ptr() as *const u8, (*self).len)) }
+    }
+
+    
+    pub fn get(self: &Self, handle: ArenaHandle) -> Option<&str> {
+        let srt_idx: usize = handle.offset();
+        let end_idx: usize = srt_idx.checked_add(handle.len())?;
+        (*self).as_str().get(srt_idx..end_idx)
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).count
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).count == 0
+    }
+
+    
+    #[inline]
+    pub fn bytes_used(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn bytes_remaining(self: &Self) -> usize {
+        BYTES - (*self).len
+    }
+
+    
+    #[inline]
+    pub fn handles(self: &Self) -> &[ArenaHandle] {
+        &(*self).handles[..(*self).count]
+    }
+
+    
+    pub fn iter(self: &Self) -> ArenaIter<'_> {
+        ArenaIter {
+            text: (*self).as_str(),
+            handles: (*self).handles().iter(),
+        }
+    }
+
+    
+    #[inline]
+    pub fn clear(self: &mut Self) {
+        (*self).len = 0;
+        (*self).count = 0;
+    }
+}
+
+
+pub struct ArenaIter<'a> {
+    text: &'a str,
+    handles: slice::Iter<'a, ArenaHandle>,
+}
+
+
+impl<'a> Iterator for ArenaIter<'a> {
+    type Item = &'a str;
+    fn next(self: &mut Self) -> Option<&'a str> {
+        let handle: &ArenaHandle = (*self).handles.next()?;
+        Some(&(*self).text[handle.offset()..handle.offset() + handle.len()])
+    }
+
+    fn size_hint(self: &Self) -> (usize, Option<usize>) {
+        (*self).handles.size_hint()
+    }
+}
+
+
+impl ExactSizeIterator for ArenaIter<'_> {}
+
+
+impl<'a, const BYTES: usize, const COUNT: usize> IntoIterator for &'a YangonArena<BYTES, COUNT> {
+    type Item = &'a str;
+    type IntoIter = ArenaIter<'a>;
+    fn into_iter(self) -> ArenaIter<'a> {
+        self.iter()
+    }
+}
+
+
+impl<const BYTES: usize, const COUNT: usize> Index<ArenaHandle> for YangonArena<BYTES, COUNT> {
+    type Output = str;
+    fn index(self: &Self, handle: ArenaHandle) -> &str {
+        match (*self).get(handle) {
+            Some(slice) => slice,
+            None => panic!("Index out of bounds."),
+        }
+    }
+}
+
+
+impl<const BYTES: usize, const COUNT: usize> Default for YangonArena<BYTES, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const BYTES: usize, const COUNT: usize> Debug for YangonArena<BYTES, COUNT> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries((*self).iter()).finish()
+    }
+}
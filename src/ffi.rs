@@ -0,0 +1,147 @@
+use crate::{yError, Yangon};
+use std::{
+    mem::{align_of, size_of},
+    os::raw::{c_char, c_int},
+    ptr,
+    slice::from_raw_parts,
+    str,
+};
+
+
+pub const YANGON_CAPACITY: usize = 256;
+
+pub const YANGON_OK: c_int = 0;
+pub const YANGON_FROM_UTF8_ERROR: c_int = 1;
+pub const YANGON_FROM_UTF16_ERROR: c_int = 2;
+pub const YANGON_ENCODING_ERROR: c_int = 3;
+pub const YANGON_NUL_ERROR: c_int = 4;
+pub const YANGON_CAPACITY_OVERFLOW: c_int = 5;
+pub const YANGON_TOO_MANY_FIELDS: c_int = 6;
+pub const YANGON_FIELD_TOO_LONG: c_int = 7;
+pub const YANGON_NULL_POINTER: c_int = -1;
+
+
+#[allow(non_camel_case_types)]
+pub type yangon_t = Yangon<YANGON_CAPACITY>;
+
+
+const _: () = assert!(size_of::<yangon_t>() == YANGON_CAPACITY + 2 * size_of::<usize>());
+const _: () = assert!(align_of::<yangon_t>() == align_of::<usize>());
+
+
+fn status(error: yError) -> c_int {
+    match error {
+        yError::FromUtf8Error => YANGON_FROM_UTF8_ERROR,
+        yError::FromUtf16Error => YANGON_FROM_UTF16_ERROR,
+        yError::EncodingError => YANGON_ENCODING_ERROR,
+        yError::NulError => YANGON_NUL_ERROR,
+        yError::CapacityOverflow => YANGON_CAPACITY_OVERFLOW,
+        yError::TooManyFields => YANGON_TOO_MANY_FIELDS,
+        yError::FieldTooLong => YANGON_FIELD_TOO_LONG,
+    }
+}
+
+
+unsafe fn as_str<'s>(slice: *const c_char, len: usize) -> Result<&'s str, c_int> {
+    if len == 0 {
+        return Ok("");
+    }
+    if slice.is_null() {
+        return Err(YANGON_NULL_POINTER);
+    }
+    str::from_utf8(from_raw_parts(slice as *const u8, len)).map_err(|_| YANGON_FROM_UTF8_ERROR)
+}
+
+
+#[no_mangle]
+pub extern "C" fn yangon_storage_size() -> usize {
+    size_of::<yangon_t>()
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_init(y: *mut yangon_t) -> c_int {
+    if y.is_null() {
+        return YANGON_NULL_POINTER;
+    }
+    ptr::write(y, Yangon::with_capacity());
+    YANGON_OK
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_push_str(y: *mut yangon_t, slice: *const c_char, len: usize) -> c_int {
+    if y.is_null() {
+        return YANGON_NULL_POINTER;
+    }
+    match as_str(slice, len) {
+        Ok(slice) => match (*y).push_str(slice) {
+            Ok(()) => YANGON_OK,
+            Err(e) => status(e),
+        },
+        Err(code) => code,
+    }
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_len(y: *const yangon_t) -> usize {
+    if y.is_null() {
+        0
+    } else {
+        (*y).len()
+    }
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_capacity(y: *const yangon_t) -> usize {
+    if y.is_null() {
+        0
+    } else {
+        (*y).capacity()
+    }
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_as_ptr(y: *const yangon_t) -> *const c_char {
+    if y.is_null() {
+        ptr::null()
+    } else {
+        (*y).as_ptr() as *const c_char
+    }
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_clear(y: *mut yangon_t) {
+    if !y.is_null() {
+        (*y).clear();
+    }
+}
+
+
+#[no_mangle]
+pub unsafe extern "C" fn yangon_replace(
+    y: *mut yangon_t,
+    from: *const c_char,
+    from_len: usize,
+    to: *const c_char,
+    to_len: usize,
+) -> c_int {
+    if y.is_null() {
+        return YANGON_NULL_POINTER;
+    }
+    let (from, to): (&str, &str) = match (as_str(from, from_len), as_str(to, to_len)) {
+        (Ok(from), Ok(to)) => (from, to),
+        (Err(code), _) | (_, Err(code)) => return code,
+    };
+    let hits: usize = (*y).as_str().matches(from).count();
+    let new_len: usize = (*y).len() - hits * from.len() + hits * to.len();
+    if new_len > (*y).capacity() {
+        return YANGON_CAPACITY_OVERFLOW;
+    }
+    *y = (*y).replace::<&str, 0>(from, to);
+    YANGON_OK
+}
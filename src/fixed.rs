@@ -0,0 +1,123 @@
+use crate::{yError, Yangon};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem::size_of,
+    slice::from_raw_parts,
+    str::{self, FromStr},
+};
+
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+pub struct FixedStr<const N: usize> {
+    len: [u8; 4],
+    bytes: [u8; N],
+}
+
+
+impl<const N: usize> FixedStr<N> {
+    
+    pub const EMPTY: Self = Self {
+        len: [0, 0, 0, 0],
+        bytes: [0; N],
+    };
+
+    
+    #[inline]
+    pub fn from_yangon<const M: usize>(yangon: &Yangon<M>) -> Result<Self, yError> {
+        yangon.as_str().parse()
+    }
+
+    
+    pub fn ref_from_bytes(bytes: &[u8]) -> Result<&Self, yError> {
+        if bytes.len() != size_of::<Self>() {
+            return Err(yError::CapacityOverflow);
+        }
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    
+    #[inline]
+    pub fn as_bytes(self: &Self) -> &[u8] {
+        unsafe { from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+
+    
+    #[inline]
+    pub fn stored_len(self: &Self) -> usize {
+        u32::from_le_bytes((*self).len) as usize
+    }
+
+    
+    pub fn as_str(self: &Self) -> Result<&str, yError> {
+        let len: usize = (*self).stored_len();
+        if len > N {
+            return Err(yError::CapacityOverflow);
+        }
+        str::from_utf8(&(*self).bytes[..len]).map_err(|_| yError::FromUtf8Error)
+    }
+
+    
+    pub fn is_zero_filled(self: &Self) -> bool {
+        let len: usize = (*self).stored_len();
+        len <= N && (*self).bytes[len..].iter().all(|&x| x == 0)
+    }
+
+    
+    pub fn to_yangon<const M: usize>(self: &Self) -> Result<Yangon<M>, yError> {
+        let slice: &str = (*self).as_str()?;
+        let mut inst: Yangon<M> = Yangon::with_capacity();
+        inst.push_str(slice)?;
+        Ok(inst)
+    }
+}
+
+
+impl<const N: usize> FromStr for FixedStr<N> {
+    type Err = yError;
+    fn from_str(slice: &str) -> Result<Self, Self::Err> {
+        let byt_len: usize = slice.len();
+        if byt_len > N || byt_len > u32::MAX as usize {
+            return Err(yError::CapacityOverflow);
+        }
+        let mut inst: Self = Self::EMPTY;
+        inst.bytes[..byt_len].copy_from_slice(slice.as_bytes());
+        inst.len = (byt_len as u32).to_le_bytes();
+        Ok(inst)
+    }
+}
+
+
+impl<const N: usize> Default for FixedStr<N> {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+
+impl<const N: usize> Debug for FixedStr<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        match (*self).as_str() {
+            Ok(slice) => write!(f, "{:?}", slice),
+            Err(_) => write!(f, "FixedStr(<corrupted, len {}>)", (*self).stored_len()),
+        }
+    }
+}
+
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for FixedStr<N> {}
+
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for FixedStr<N> {}
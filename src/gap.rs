@@ -0,0 +1,282 @@
+use crate::{yError, yMotion, Yangon};
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    str::from_utf8_unchecked,
+};
+
+
+fn is_word(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+
+#[derive(Clone)]
+pub struct GapYangon<const N: usize> {
+    list: [u8; N],
+    gap_srt: usize,
+    gap_end: usize,
+    cursor: usize,
+}
+
+
+impl<const N: usize> GapYangon<N> {
+    
+    pub const fn new() -> Self {
+        Self {
+            list: [0; N],
+            gap_srt: 0,
+            gap_end: N,
+            cursor: 0,
+        }
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        N - ((*self).gap_end - (*self).gap_srt)
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len() == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        N
+    }
+
+    
+    #[inline]
+    pub fn cursor(self: &Self) -> usize {
+        (*self).cursor
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).gap_srt = 0;
+        (*self).gap_end = N;
+        (*self).cursor = 0;
+    }
+
+    
+    pub fn as_slices(self: &Self) -> (&str, &str) {
+        let list: &[u8; N] = &(*self).list;
+        unsafe {
+            (
+                from_utf8_unchecked(&list[..(*self).gap_srt]),
+                from_utf8_unchecked(&list[(*self).gap_end..]),
+            )
+        }
+    }
+
+    
+    pub fn as_str(self: &mut Self) -> &str {
+        let len: usize = (*self).len();
+        (*self).move_gap(len);
+        unsafe { from_utf8_unchecked(&(*self).list[..len]) }
+    }
+
+    
+    fn move_gap(self: &mut Self, to: usize) {
+        let gap_srt: usize = (*self).gap_srt;
+        let gap_end: usize = (*self).gap_end;
+        if to < gap_srt {
+            let cnt: usize = gap_srt - to;
+            (*self).list.copy_within(to..gap_srt, gap_end - cnt);
+            (*self).gap_srt -= cnt;
+            (*self).gap_end -= cnt;
+        } else if to > gap_srt {
+            let cnt: usize = to - gap_srt;
+            (*self).list.copy_within(gap_end..gap_end + cnt, gap_srt);
+            (*self).gap_srt += cnt;
+            (*self).gap_end += cnt;
+        }
+    }
+
+    
+    fn char_before(self: &Self) -> Option<char> {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        let cursor: usize = (*self).cursor;
+        if cursor <= frt.len() {
+            frt[..cursor].chars().next_back()
+        } else {
+            lst[..cursor - frt.len()].chars().next_back()
+        }
+    }
+
+    
+    fn char_after(self: &Self) -> Option<char> {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        let cursor: usize = (*self).cursor;
+        if cursor < frt.len() {
+            frt[cursor..].chars().next()
+        } else {
+            lst[cursor - frt.len()..].chars().next()
+        }
+    }
+
+    
+    pub fn set_cursor(self: &mut Self, idx: usize) {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        let on_boundary: bool = if idx <= frt.len() {
+            frt.is_char_boundary(idx)
+        } else {
+            lst.is_char_boundary(idx - frt.len())
+        };
+        if !on_boundary {
+            panic!("Index out of bounds.");
+        }
+        (*self).cursor = idx;
+    }
+
+    
+    pub fn move_cursor_left(self: &mut Self, motion: yMotion) -> bool {
+        let start: usize = (*self).cursor;
+        match motion {
+            yMotion::Char => {
+                if let Some(ch) = (*self).char_before() {
+                    (*self).cursor -= ch.len_utf8();
+                }
+            }
+            yMotion::Word => {
+                while let Some(ch) = (*self).char_before() {
+                    if is_word(ch) {
+                        break;
+                    }
+                    (*self).cursor -= ch.len_utf8();
+                }
+                while let Some(ch) = (*self).char_before() {
+                    if !is_word(ch) {
+                        break;
+                    }
+                    (*self).cursor -= ch.len_utf8();
+                }
+            }
+        }
+        (*self).cursor != start
+    }
+
+    
+    pub fn move_cursor_right(self: &mut Self, motion: yMotion) -> bool {
+        let start: usize = (*self).cursor;
+        match motion {
+            yMotion::Char => {
+                if let Some(ch) = (*self).char_after() {
+                    (*self).cursor += ch.len_utf8();
+                }
+            }
+            yMotion::Word => {
+                while let Some(ch) = (*self).char_after() {
+                    if is_word(ch) {
+                        break;
+                    }
+                    (*self).cursor += ch.len_utf8();
+                }
+                while let Some(ch) = (*self).char_after() {
+                    if !is_word(ch) {
+                        break;
+                    }
+                    (*self).cursor += ch.len_utf8();
+                }
+            }
+        }
+        (*self).cursor != start
+    }
+
+    
+    pub fn insert(self: &mut Self, ch: char) -> Result<(), yError> {
+        let mut buf: [u8; 4] = [0; 4];
+        (*self).insert_str(ch.encode_utf8(&mut buf))
+    }
+
+    
+    pub fn insert_str(self: &mut Self, slice: &str) -> Result<(), yError> {
+        if slice.len() > (*self).gap_end - (*self).gap_srt {
+            return Err(yError::CapacityOverflow);
+        }
+        let cursor: usize = (*self).cursor;
+        (*self).move_gap(cursor);
+        let gap_srt: usize = (*self).gap_srt;
+        (*self).list[gap_srt..gap_srt + slice.len()].copy_from_slice(slice.as_bytes());
+        (*self).gap_srt += slice.len();
+        (*self).cursor += slice.len();
+        Ok(())
+    }
+
+    
+    pub fn delete_backward(self: &mut Self) -> Option<char> {
+        let ch: char = (*self).char_before()?;
+        let cursor: usize = (*self).cursor;
+        (*self).move_gap(cursor);
+        (*self).gap_srt -= ch.len_utf8();
+        (*self).cursor -= ch.len_utf8();
+        Some(ch)
+    }
+
+    
+    pub fn delete_forward(self: &mut Self) -> Option<char> {
+        let ch: char = (*self).char_after()?;
+        let cursor: usize = (*self).cursor;
+        (*self).move_gap(cursor);
+        (*self).gap_end += ch.len_utf8();
+        Some(ch)
+    }
+
+    
+    pub fn to_yangon(self: &Self) -> Yangon<N> {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        let mut inst: Yangon<N> = Yangon::with_capacity();
+        let _ = inst.push_str(frt);
+        let _ = inst.push_str(lst);
+        inst
+    }
+}
+
+
+impl<const N: usize> Default for GapYangon<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize> From<Yangon<N>> for GapYangon<N> {
+    fn from(value: Yangon<N>) -> Self {
+        let mut inst: Self = Self::new();
+        let _ = inst.insert_str(value.as_str());
+        inst
+    }
+}
+
+
+impl<const N: usize> Write for GapYangon<N> {
+    fn write_str(self: &mut Self, s: &str) -> FmtResult {
+        (*self).insert_str(s).map_err(|_| std::fmt::Error)
+    }
+}
+
+
+impl<const N: usize> Display for GapYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        f.write_str(frt)?;
+        f.write_str(lst)
+    }
+}
+
+
+impl<const N: usize> Debug for GapYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        f.write_char('"')?;
+        for ch in frt.chars().chain(lst.chars()) {
+            for x in ch.escape_debug() {
+                f.write_char(x)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{yError, ArenaHandle, YangonArena};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+
+const EMPTY: u32 = u32::MAX;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Symbol(u32);
+
+
+impl Symbol {
+    
+    #[inline]
+    pub fn as_u32(self: &Self) -> u32 {
+        (*self).0
+    }
+
+    
+    #[inline]
+    pub fn index(self: &Self) -> usize {
+        (*self).0 as usize
+    }
+}
+
+
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+    for &x in bytes {
+        hash ^= x as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01B3);
+    }
+    hash
+}
+
+
+#[derive(Clone)]
+pub struct YangonInterner<const BYTES: usize, const SLOTS: usize> {
+    arena: YangonArena<BYTES, SLOTS>,
+    table: [u32; SLOTS],
+}
+
+
+impl<const BYTES: usize, const SLOTS: usize> YangonInterner<BYTES, SLOTS> {
+    
+    const HAS_SLOTS: () =
+        assert!(SLOTS > 0 && SLOTS < u32::MAX as usize, "YangonInterner needs 1..u32::MAX slots");
+
+    
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::HAS_SLOTS;
+        Self {
+            arena: YangonArena::new(),
+            table: [EMPTY; SLOTS],
+        }
+    }
+
+    
+    fn probe(self: &Self, slice: &str) -> Result<Symbol, usize> {
+        let mut idx: usize = (fnv1a(slice.as_bytes()) % SLOTS as u64) as usize;
+        for _ in 0..SLOTS {
+            let sym: u32 = (*self).table[idx];
+            if sym == EMPTY {
+                return Err(idx);
+            }
+            if (*self).resolve(Symbol(sym)) == Some(slice) {
+                return Ok(Symbol(sym));
+            }
+            idx = if idx + 1 == SLOTS { 0 } else { idx + 1 };
+        }
+        Err(SLOTS)
+    }
+
+    
+    pub fn intern(self: &mut Self, slice: &str) -> Result<Symbol, yError> {
+        let idx: usize = match (*self).probe(slice) {
+            Ok(sym) => return Ok(sym),
+            Err(idx) => idx,
+        };
+        if idx == SLOTS {
+            return Err(yError::CapacityOverflow);
+        }
+        let sym: u32 = (*self).arena.len() as u32;
+        (*self).arena.insert(slice)?;
+        (*self).table[idx] = sym;
+        Ok(Symbol(sym))
+    }
+
+    
+    #[inline]
+    pub fn get(self: &Self, slice: &str) -> Option<Symbol> {
+        (*self).probe(slice).ok()
+    }
+
+    
+    pub fn resolve(self: &Self, sym: Symbol) -> Option<&str> {
+        let handle: ArenaHandle = *(*self).arena.handles().get(sym.index())?;
+        (*self).arena.get(handle)
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).arena.len()
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).arena.is_empty()
+    }
+
+    
+    #[inline]
+    pub fn is_full(self: &Self) -> bool {
+        (*self).arena.len() == SLOTS
+    }
+
+    
+    #[inline]
+    pub fn bytes_remaining(self: &Self) -> usize {
+        (*self).arena.bytes_remaining()
+    }
+
+    
+    pub fn iter(self: &Self) -> impl Iterator<Item = (Symbol, &str)> + '_ {
+        (*self).arena.iter().enumerate().map(|(idx, slice)| (Symbol(idx as u32), slice))
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).arena.clear();
+        (*self).table = [EMPTY; SLOTS];
+    }
+}
+
+
+impl<const BYTES: usize, const SLOTS: usize> Default for YangonInterner<BYTES, SLOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const BYTES: usize, const SLOTS: usize> Debug for YangonInterner<BYTES, SLOTS> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries((*self).iter().map(|(sym, slice)| (sym.0, slice))).finish()
+    }
+}
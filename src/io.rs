@@ -0,0 +1,129 @@
+use crate::{yError, Utf8Appender, Yangon};
+use std::io::{BufRead, Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+
+
+pub struct YangonReader<'r, const N: usize> {
+    source: &'r Yangon<N>,
+    pos: usize,
+}
+
+
+impl<'r, const N: usize> YangonReader<'r, N> {
+    
+    #[inline]
+    pub fn new(source: &'r Yangon<N>) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    
+    #[inline]
+    pub fn position(self: &Self) -> usize {
+        (*self).pos
+    }
+}
+
+
+impl<const N: usize> Read for YangonReader<'_, N> {
+    fn read(self: &mut Self, buf: &mut [u8]) -> IoResult<usize> {
+        let rest: &[u8] = (*self).fill_buf()?;
+        let byt_len: usize = if rest.len() < buf.len() { rest.len() } else { buf.len() };
+        buf[..byt_len].copy_from_slice(&rest[..byt_len]);
+        (*self).consume(byt_len);
+        Ok(byt_len)
+    }
+}
+
+
+impl<const N: usize> BufRead for YangonReader<'_, N> {
+    fn fill_buf(self: &mut Self) -> IoResult<&[u8]> {
+        Ok(&(*self).source.as_bytes()[(*self).pos..])
+    }
+
+    fn consume(self: &mut Self, amt: usize) {
+        let len: usize = (*self).source.len();
+        (*self).pos = if (*self).pos + amt > len { len } else { (*self).pos + amt };
+    }
+}
+
+
+pub(crate) fn to_io_error(error: yError) -> IoError {
+    match error {
+        yError::CapacityOverflow => IoError::new(ErrorKind::WriteZero, "Capacity Overflow."),
+        _ => IoError::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8"),
+    }
+}
+
+
+impl<const N: usize> Write for Utf8Appender<'_, N> {
+    fn write(self: &mut Self, buf: &[u8]) -> IoResult<usize> {
+        match (*self).push_prefix(buf) {
+            Ok(0) if !buf.is_empty() => Err(to_io_error(yError::CapacityOverflow)),
+            Ok(byt_len) => Ok(byt_len),
+            Err(e) => Err(to_io_error(e)),
+        }
+    }
+
+    fn flush(self: &mut Self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+
+fn char_len(lead: u8) -> usize {
+    if lead < 0xE0 {
+        2
+    } else if lead < 0xF0 {
+        3
+    } else {
+        4
+    }
+}
+
+
+pub(crate) fn read_line_into<R: BufRead, const N: usize>(
+    mut app: Utf8Appender<'_, N>,
+    reader: &mut R,
+) -> IoResult<usize> {
+    let mut read: usize = 0;
+    loop {
+        let chunk: &[u8] = match reader.fill_buf() {
+            Ok(chunk) => chunk,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if chunk.is_empty() {
+            break;
+        }
+        let (mut take, mut done): (usize, bool) = match chunk.iter().position(|&x| x == b'\n') {
+            Some(idx) => (idx + 1, true),
+            None => (chunk.len(), false),
+        };
+        let room: usize = app.room();
+        if take > room {
+            take = room;
+            done = true;
+            while take > 0 && chunk[take] & 0xC0 == 0x80 {
+                take -= 1;
+            }
+        }
+        let mut lead: usize = take;
+        while lead > 0 && take - lead < 4 && chunk[lead - 1] & 0xC0 == 0x80 {
+            lead -= 1;
+        }
+        if lead > 0 && chunk[lead - 1] >= 0xC0 {
+            let byt_len: usize = char_len(chunk[lead - 1]);
+            if lead - 1 + byt_len > take && lead - 1 + byt_len > room {
+                take = lead - 1;
+                done = true;
+            }
+        }
+        app.push_bytes(&chunk[..take]).map_err(to_io_error)?;
+        reader.consume(take);
+        read += take;
+        if done {
+            break;
+        }
+    }
+    app.finish().map_err(to_io_error)?;
+    Ok(read)
+}
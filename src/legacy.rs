@@ -0,0 +1,46 @@
+const WINDOWS_1252: [u16; 32] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F,
+    0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+];
+
+
+#[inline]
+pub(crate) fn latin1_to_char(x: u8) -> char {
+    x as char
+}
+
+
+#[inline]
+pub(crate) fn windows_1252_to_char(x: u8) -> char {
+    if (0x80..0xA0).contains(&x) {
+        unsafe { char::from_u32_unchecked(WINDOWS_1252[(x - 0x80) as usize] as u32) }
+    } else {
+        x as char
+    }
+}
+
+
+#[inline]
+pub(crate) fn char_to_latin1(ch: char) -> Option<u8> {
+    if (ch as u32) < 0x100 {
+        Some(ch as u8)
+    } else {
+        None
+    }
+}
+
+
+pub(crate) fn char_to_windows_1252(ch: char) -> Option<u8> {
+    let code: u32 = ch as u32;
+    if code < 0x80 || (0xA0..0x100).contains(&code) {
+        return Some(code as u8);
+    }
+    for (idx, &x) in WINDOWS_1252.iter().enumerate() {
+        if x as u32 == code {
+            return Some(0x80 + idx as u8);
+        }
+    }
+    None
+}
@@ -1,13 +1,62 @@
+#![allow(clippy::needless_arbitrary_self_type, clippy::explicit_auto_deref, clippy::missing_safety_doc)]
+
+mod appender;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod arena;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+mod gap;
+mod interner;
+mod io;
+mod legacy;
+mod line;
+mod map;
+mod nul;
+#[cfg(kani)]
+mod proofs;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod ring;
+mod rope;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod small;
+mod vec;
+mod width;
+mod wtf8;
+
+pub use appender::Utf8Appender;
+pub use arena::{ArenaHandle, ArenaIter, YangonArena};
+pub use fixed::FixedStr;
+pub use gap::GapYangon;
+pub use interner::{Symbol, YangonInterner};
+pub use io::YangonReader;
+pub use line::LineBuffer;
+pub use map::{SortedYangonMap, SortedYangonSet, YangonMap, YangonSet};
+pub use nul::NulYangon;
+pub use ring::RingYangon;
+pub use rope::YangonRope;
+#[cfg(feature = "alloc")]
+pub use small::SmallYangon;
+pub use vec::YangonVec;
+pub use wtf8::{CodePoints, Wtf8Yangon};
+
 use std::{
-    cmp::PartialEq,
+    borrow::{Borrow, Cow},
+    ffi::CStr,
+    cmp::{Ordering, PartialEq},
     convert::AsRef,
+    hash::{Hash, Hasher},
     slice::from_raw_parts,
     fmt::{Debug, Display, Error as FmtError, Formatter, Result as FmtResult, Write},
     mem::{MaybeUninit, transmute},
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut},
     ops::{Bound, RangeBounds},
     result::Result,
-    str::{self, from_utf8_unchecked, from_utf8_unchecked_mut},
+    str::{self, from_utf8_unchecked, from_utf8_unchecked_mut, FromStr},
 };
 
 
@@ -38,11 +87,87 @@ pub enum yPattern<'y, const C: usize> {
 #[allow(non_camel_case_types)]
 pub enum yError {
     FromUtf8Error,
+    FromUtf16Error,
+    EncodingError,
+    NulError,
     CapacityOverflow,
+    TooManyFields,
+    FieldTooLong,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yUnmappable {
+    Error,
+    Replace(u8),
+    Skip,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yPadding {
+    Nul,
+    Space,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yAlign {
+    Left,
+    Right,
+    Center,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yEvict {
+    Char,
+    Line,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yMotion {
+    Char,
+    Word,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yTerminator<'t> {
+    Lf,
+    CrLf,
+    Custom(&'t str),
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum yPolicy<'p> {
+    ReplaceChar(char),
+    ReplaceStr(&'p str),
+    Skip,
+    Escape,
+    Stop,
 }
 
 
 #[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct yReport {
+    pub invalid_sequences: usize,
+    pub truncated_bytes: usize,
+}
+
+
+#[allow(non_camel_case_types)]
+#[derive(Clone)]
 pub enum yCow<'c, X> {
     Borrowed(&'c str),
     Owned(X),
@@ -153,81 +278,49 @@ impl<const N: usize> Yangon<N> {
     where
         R: RangeBounds<usize>,
     {
-        let mut list_len: usize = (*self).len;
-        let mut str_idx: usize = match range.start_bound() {
+        let len: usize = (*self).len;
+        let str_idx: usize = match range.start_bound() {
             Bound::Unbounded => 0,
             Bound::Included(&i) => i,
             Bound::Excluded(&e) => e + 1,
         };
         let end_idx: usize = match range.end_bound() {
-            Bound::Unbounded => list_len,
+            Bound::Unbounded => len,
             Bound::Excluded(&e) => e,
             Bound::Included(&i) => i + 1,
         };
-        let list: &mut [MaybeUninit<u8>] = &mut (*self).list;
-        let slice_ptr: *const u8 = slice.as_ptr();
+        if str_idx > end_idx
+            || end_idx > len
+            || !(*self).as_str().is_char_boundary(str_idx)
+            || !(*self).as_str().is_char_boundary(end_idx)
+        {
+            panic!("Index out of bounds.");
+        }
         let slice_len: usize = slice.len();
-        let mut counter: usize = 0;
-        if slice_len <= end_idx - str_idx {
-            let mut times_to_loop: usize = 0;
-            for x in str_idx..end_idx {
-                if counter < slice_len {
-                    unsafe {
-                        *(*list)[x].as_mut_ptr() = *slice_ptr.add(counter);
-                    }
-                    counter += 1;
-                } else {
-                    unsafe {
-                        *(*list)[x].as_mut_ptr() = 0;
-                    }
-                    times_to_loop += 1;
-                }
-            }
-            let mut watch: usize = 0;
-            while watch < times_to_loop {
-                unsafe {
-                    for x in 0..list_len {
-                        if transmute::<MaybeUninit<u8>, u8>((*list)[x]) == 0 {
-                            (*list)[x] = (*list)[x + 1];
-                            *(*list)[x + 1].as_mut_ptr() = 0;
-                        }
-                    }
-                    watch += 1;
-                    list_len -= 1;
+        let new_len: usize = len - (end_idx - str_idx) + slice_len;
+        if new_len > (*self).capacity {
+            panic!("Capacity Overflow.");
+        }
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
+        let tail: usize = len - end_idx;
+        let new_end: usize = str_idx + slice_len;
+        unsafe {
+            if new_end > end_idx {
+                let mut x: usize = tail;
+                while x > 0 {
+                    x -= 1;
+                    *ptr.add(new_end + x) = *ptr.add(end_idx + x);
                 }
-            }
-            (*self).len = list_len;
-        } else {
-            for x in 0..slice_len - (end_idx - str_idx) {
-                unsafe {
-                    *(*list)[list_len].as_mut_ptr() = 0;
+            } else {
+                for x in 0..tail {
+                    *ptr.add(new_end + x) = *ptr.add(end_idx + x);
                 }
-                list_len += 1;
             }
-            loop {
-                unsafe {
-                    if transmute::<MaybeUninit<u8>, u8>((*list)[end_idx]) == 0 {
-                        break;
-                    } else {
-                        for x in 0..list_len {
-                            unsafe {
-                                if transmute::<MaybeUninit<u8>, u8>((*list)[x]) == 0 {
-                                    (*list)[x] = (*list)[x - 1];
-                                    *(*list)[x - 1].as_mut_ptr() = 0;
-                                }
-                            }
-                        }
-                    }
-                }
+            for (x, &y) in slice.as_bytes().iter().enumerate() {
+                *ptr.add(str_idx + x) = y;
             }
-            for &x in slice.as_bytes() {
-                unsafe {
-                    *(*list)[str_idx].as_mut_ptr() = x;
-                }
-                str_idx += 1;
-            }
-            (*self).len = list_len;
         }
+        (*self).len = new_len;
     }
 
     
@@ -308,6 +401,9 @@ impl<const N: usize> Yangon<N> {
     #[inline]
     pub fn truncate(self: &mut Self, t_cate: usize) {
         if t_cate <= (*self).len {
+            if !(*self).as_str().is_char_boundary(t_cate) {
+                panic!("Index out of bounds.");
+            }
             (*self).len = t_cate;
         }
     }
@@ -376,59 +472,320 @@ impl<const N: usize> Yangon<N> {
         if str::from_utf8(list_ref).is_ok() {
             yCow::Borrowed(unsafe { from_utf8_unchecked(list_ref) })
         } else {
-            let mut inst: Self = Self::with_capacity();
-            let ptr: *mut u8 = inst.list.as_mut_ptr() as *mut u8;
-            let len: usize = list_ref.len();
-            let mut srt_idx: usize = 0;
-            let mut idx: usize = 0;
-            loop {
-                match str::from_utf8(&(*list_ref)[srt_idx..]) {
-                    Ok(slice) => {
-                        for &x in &(*list_ref)[srt_idx..] {
-                            unsafe {
-                                *ptr.add(idx) = x;
-                            }
-                            idx += 1;
-                        }
-                        break;
-                    }
-                    Err(e) => {
-                        let err_srt: usize = e.valid_up_to();
-                        for &x in &(*list_ref)[srt_idx..srt_idx + err_srt] {
+            yCow::Owned(Self::from_utf8_with(list_ref, yPolicy::ReplaceChar('\u{FFFD}')).0)
+        }
+    }
+
+    
+    fn push_fitting(self: &mut Self, slice: &str) -> usize {
+        let room: usize = (*self).capacity - (*self).len;
+        let mut end_idx: usize = if slice.len() < room { slice.len() } else { room };
+        while !slice.is_char_boundary(end_idx) {
+            end_idx -= 1;
+        }
+        unsafe {
+            (*self).push_str_unchecked(&slice[..end_idx]);
+        }
+        end_idx
+    }
+
+    
+    pub fn from_utf8_with(list_ref: &[u8], policy: yPolicy<'_>) -> (Self, yReport) {
+        let mut inst: Self = Self::with_capacity();
+        let mut report: yReport = yReport::default();
+        let len: usize = list_ref.len();
+        let mut srt_idx: usize = 0;
+        while srt_idx < len {
+            let (valid, err_len): (&str, usize) = match str::from_utf8(&(*list_ref)[srt_idx..]) {
+                Ok(slice) => (slice, 0),
+                Err(e) => (
+                    unsafe { from_utf8_unchecked(&(*list_ref)[srt_idx..srt_idx + e.valid_up_to()]) },
+                    e.error_len().unwrap_or(len - srt_idx - e.valid_up_to()),
+                ),
+            };
+            let written: usize = inst.push_fitting(valid);
+            srt_idx += written;
+            if written < valid.len() {
+                break;
+            }
+            if err_len == 0 {
+                continue;
+            }
+            report.invalid_sequences += 1;
+            let fits: bool = match policy {
+                yPolicy::ReplaceChar(ch) => inst.push(ch).is_ok(),
+                yPolicy::ReplaceStr(slice) => inst.push_str(slice).is_ok(),
+                yPolicy::Skip => true,
+                yPolicy::Escape => {
+                    if inst.len + err_len * 4 > inst.capacity {
+                        false
+                    } else {
+                        let hex: &[u8; 16] = b"0123456789ABCDEF";
+                        for &x in &(*list_ref)[srt_idx..srt_idx + err_len] {
                             unsafe {
-                                *ptr.add(idx) = x;
-                            }
-                            idx += 1;
-                        }
-                        match e.error_len() {
-                            Some(err_len) => {
-                                for x in [0xEF, 0xBF, 0xBD] {
-                                    unsafe {
-                                        *ptr.add(idx) = x;
-                                    }
-                                    idx += 1;
-                                }
-                                srt_idx += err_srt + err_len;
-                                if srt_idx >= len {
-                                    break;
-                                }
-                            }
-                            _ => {
-                                for x in [0xEF, 0xBF, 0xBD] {
-                                    unsafe {
-                                        *ptr.add(idx) = x;
-                                    }
-                                    idx += 1;
-                                }
-                                break;
+                                inst.push_str_unchecked(from_utf8_unchecked(&[
+                                    b'\\',
+                                    b'x',
+                                    (*hex)[(x >> 4) as usize],
+                                    (*hex)[(x & 0x0F) as usize],
+                                ]));
                             }
                         }
+                        true
                     }
                 }
+                yPolicy::Stop => false,
+            };
+            if !fits {
+                break;
             }
-            inst.len = idx;
-            yCow::Owned(inst)
+            srt_idx += err_len;
         }
+        report.truncated_bytes = len - srt_idx;
+        (inst, report)
+    }
+
+    
+    fn push_utf16<I>(self: &mut Self, units: I, lossy: bool) -> Result<(), yError>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        for x in char::decode_utf16(units) {
+            let ch: char = match x {
+                Ok(ch) => ch,
+                Err(_) if lossy => '\u{FFFD}',
+                Err(_) => return Err(yError::FromUtf16Error),
+            };
+            if lossy && (*self).len + ch.len_utf8() > (*self).capacity {
+                break;
+            }
+            (*self).push(ch)?;
+        }
+        Ok(())
+    }
+
+    
+    pub fn from_utf16(list: &[u16]) -> Result<Self, yError> {
+        let mut inst: Self = Self::with_capacity();
+        inst.push_utf16(list.iter().copied(), false)?;
+        Ok(inst)
+    }
+
+    
+    pub unsafe fn from_utf16_unchecked(list: &[u16]) -> Self {
+        let mut inst: Self = Self::with_capacity();
+        let ptr: *mut u8 = inst.list.as_mut_ptr() as *mut u8;
+        let mut bind: [u8; 4] = [0, 0, 0, 0];
+        for x in char::decode_utf16(list.iter().copied()) {
+            let ch: char = match x {
+                Ok(ch) => ch,
+                Err(_) => std::hint::unreachable_unchecked(),
+            };
+            for &y in ch.encode_utf8(&mut bind).as_bytes() {
+                *ptr.add(inst.len) = y;
+                inst.len += 1;
+            }
+        }
+        inst
+    }
+
+    
+    pub fn from_utf16_lossy(list: &[u16]) -> Self {
+        let mut inst: Self = Self::with_capacity();
+        let _ = inst.push_utf16(list.iter().copied(), true);
+        inst
+    }
+
+    
+    pub fn from_utf16le(bytes: &[u8]) -> Result<Self, yError> {
+        if bytes.len() % 2 != 0 {
+            return Err(yError::FromUtf16Error);
+        }
+        let mut inst: Self = Self::with_capacity();
+        inst.push_utf16(bytes.chunks_exact(2).map(|x| u16::from_le_bytes([x[0], x[1]])), false)?;
+        Ok(inst)
+    }
+
+    
+    pub fn from_utf16be(bytes: &[u8]) -> Result<Self, yError> {
+        if bytes.len() % 2 != 0 {
+            return Err(yError::FromUtf16Error);
+        }
+        let mut inst: Self = Self::with_capacity();
+        inst.push_utf16(bytes.chunks_exact(2).map(|x| u16::from_be_bytes([x[0], x[1]])), false)?;
+        Ok(inst)
+    }
+
+    
+    pub fn encode_utf16<const M: usize>(self: &Self) -> Result<([u16; M], usize), yError> {
+        let mut list: [u16; M] = [0; M];
+        let mut idx: usize = 0;
+        for x in (*self).as_str().encode_utf16() {
+            if idx >= M {
+                return Err(yError::CapacityOverflow);
+            }
+            list[idx] = x;
+            idx += 1;
+        }
+        Ok((list, idx))
+    }
+
+    
+    fn push_decoded(self: &mut Self, bytes: &[u8], decode: fn(u8) -> char) -> Result<(), yError> {
+        let mut byt_len: usize = 0;
+        for &x in bytes {
+            byt_len += decode(x).len_utf8();
+        }
+        if (*self).len + byt_len > (*self).capacity {
+            return Err(yError::CapacityOverflow);
+        }
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
+        let mut bind: [u8; 4] = [0, 0, 0, 0];
+        let mut len: usize = (*self).len;
+        for &x in bytes {
+            for &y in decode(x).encode_utf8(&mut bind).as_bytes() {
+                unsafe {
+                    *ptr.add(len) = y;
+                }
+                len += 1;
+            }
+        }
+        (*self).len = len;
+        Ok(())
+    }
+
+    
+    pub fn from_latin1(bytes: &[u8]) -> Result<Self, yError> {
+        let mut inst: Self = Self::with_capacity();
+        inst.push_decoded(bytes, legacy::latin1_to_char)?;
+        Ok(inst)
+    }
+
+    
+    pub fn from_windows_1252(bytes: &[u8]) -> Result<Self, yError> {
+        let mut inst: Self = Self::with_capacity();
+        inst.push_decoded(bytes, legacy::windows_1252_to_char)?;
+        Ok(inst)
+    }
+
+    
+    pub fn from_ascii(bytes: &[u8]) -> Result<Self, yError> {
+        if !bytes.is_ascii() {
+            return Err(yError::EncodingError);
+        }
+        let mut inst: Self = Self::with_capacity();
+        inst.push_decoded(bytes, legacy::latin1_to_char)?;
+        Ok(inst)
+    }
+
+    
+    fn encode_with<const M: usize>(
+        self: &Self,
+        encode: fn(char) -> Option<u8>,
+        policy: yUnmappable,
+    ) -> Result<([u8; M], usize), yError> {
+        let mut list: [u8; M] = [0; M];
+        let mut idx: usize = 0;
+        for ch in (*self).as_str().chars() {
+            let x: u8 = match (encode(ch), policy) {
+                (Some(x), _) => x,
+                (None, yUnmappable::Replace(x)) => x,
+                (None, yUnmappable::Skip) => continue,
+                (None, yUnmappable::Error) => return Err(yError::EncodingError),
+            };
+            if idx >= M {
+                return Err(yError::CapacityOverflow);
+            }
+            list[idx] = x;
+            idx += 1;
+        }
+        Ok((list, idx))
+    }
+
+    
+    pub fn encode_latin1<const M: usize>(self: &Self, policy: yUnmappable) -> Result<([u8; M], usize), yError> {
+        (*self).encode_with(legacy::char_to_latin1, policy)
+    }
+
+    
+    pub fn encode_windows_1252<const M: usize>(self: &Self, policy: yUnmappable) -> Result<([u8; M], usize), yError> {
+        (*self).encode_with(legacy::char_to_windows_1252, policy)
+    }
+
+    
+    #[inline]
+    pub fn reader(self: &Self) -> YangonReader<'_, N> {
+        YangonReader::new(self)
+    }
+
+    
+    #[inline]
+    pub fn writer(self: &mut Self) -> Utf8Appender<'_, N> {
+        Utf8Appender::new(self)
+    }
+
+    
+    pub fn read_line_from<R: std::io::BufRead>(self: &mut Self, reader: &mut R) -> std::io::Result<usize> {
+        let srt_len: usize = (*self).len;
+        let result: std::io::Result<usize> = io::read_line_into(Utf8Appender::new(self), reader);
+        if result.is_err() {
+            (*self).len = srt_len;
+        }
+        result
+    }
+
+    
+    pub fn from_padded<const M: usize>(field: &[u8; M], padding: yPadding) -> Result<Self, yError> {
+        let end_idx: usize = match padding {
+            yPadding::Nul => field.iter().position(|&x| x == 0).unwrap_or(M),
+            yPadding::Space => M - field.iter().rev().take_while(|&&x| x == b' ').count(),
+        };
+        match str::from_utf8(&field[..end_idx]) {
+            Ok(slice) => {
+                let mut inst: Self = Self::with_capacity();
+                inst.push_str(slice)?;
+                Ok(inst)
+            }
+            Err(_) => Err(yError::FromUtf8Error),
+        }
+    }
+
+    
+    pub fn to_padded(self: &Self, padding: yPadding) -> [u8; N] {
+        let mut field: [u8; N] = match padding {
+            yPadding::Nul => [0; N],
+            yPadding::Space => [b' '; N],
+        };
+        field[..(*self).len].copy_from_slice((*self).as_bytes());
+        field
+    }
+
+    
+    pub fn from_c_buf(buf: &[u8]) -> Result<Self, yError> {
+        match CStr::from_bytes_until_nul(buf) {
+            Ok(c_str) => match c_str.to_str() {
+                Ok(slice) => {
+                    let mut inst: Self = Self::with_capacity();
+                    inst.push_str(slice)?;
+                    Ok(inst)
+                }
+                Err(_) => Err(yError::FromUtf8Error),
+            },
+            Err(_) => Err(yError::NulError),
+        }
+    }
+
+    
+    pub fn as_c_str(self: &mut Self) -> Result<&CStr, yError> {
+        let len: usize = (*self).len;
+        if len >= N {
+            return Err(yError::CapacityOverflow);
+        }
+        unsafe {
+            *(*self).list[len].as_mut_ptr() = 0;
+        }
+        let bytes: &[u8] = unsafe { from_raw_parts((*self).as_ptr(), len + 1) };
+        CStr::from_bytes_with_nul(bytes).map_err(|_| yError::NulError)
     }
 
     
@@ -443,21 +800,18 @@ impl<const N: usize> Yangon<N> {
         let mut bind: [u8; 4] = [0, 0, 0, 0];
         let bytes: &[u8] = ch.encode_utf8(&mut bind).as_bytes();
         let byt_len: usize = bytes.len();
-        if idx > len {
+        if idx > len || !(*self).as_str().is_char_boundary(idx) {
             panic!("Index out of bounds.");
         } else if len + byt_len > (*self).capacity {
             panic!("Capacity Overflow.")
         } else {
             let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
-            let jumps: usize = len - idx;
-            let mut lst_idx: usize = len - 1 + byt_len;
-            let mut edg_idx: isize = (len - 1) as isize;
-            for _ in 0..jumps {
+            let mut edg_idx: usize = len;
+            while edg_idx > idx {
+                edg_idx -= 1;
                 unsafe {
-                    *ptr.add(lst_idx) = *ptr.offset(edg_idx);
+                    *ptr.add(edg_idx + byt_len) = *ptr.add(edg_idx);
                 }
-                lst_idx -= 1;
-                edg_idx -= 1;
             }
             for &x in bytes {
                 unsafe {
@@ -474,59 +828,37 @@ impl<const N: usize> Yangon<N> {
     where
         F: FnMut(char) -> bool,
     {
-        let mut list_len: usize = (*self).len;
-        let list: &mut [u8] = unsafe {
-            &mut *transmute::<(*mut u8, usize), *mut [u8]>(((*self).as_mut_ptr(), list_len))
-        };
+        let len: usize = (*self).len;
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
         let mut srt_idx: usize = 0;
-        let mut end_idx: usize = 1;
-        let mut zero: usize = 0;
-        loop {
-            if srt_idx >= list_len {
-                break;
-            }
-            match str::from_utf8(&(*list)[srt_idx..end_idx]) {
-                Ok(slice) => {
-                    if !closure(slice.chars().next().unwrap()) {
-                        let mut idx: usize = srt_idx;
-                        for x in srt_idx..end_idx {
-                            (*list)[idx] = 0;
-                            zero += 1;
-                            idx += 1;
-                        }
+        let mut wrt_idx: usize = 0;
+        (*self).len = 0;
+        while srt_idx < len {
+            let ch: char = unsafe {
+                from_utf8_unchecked(from_raw_parts(ptr.add(srt_idx), len - srt_idx))
+                    .chars()
+                    .next()
+                    .unwrap()
+            };
+            let ch_len: usize = ch.len_utf8();
+            if closure(ch) {
+                for x in 0..ch_len {
+                    unsafe {
+                        *ptr.add(wrt_idx + x) = *ptr.add(srt_idx + x);
                     }
-                    srt_idx = end_idx;
-                    end_idx += 1;
-                }
-                Err(_) => {
-                    end_idx += 1;
                 }
+                wrt_idx += ch_len;
             }
+            srt_idx += ch_len;
         }
-        let reality: usize = list_len - zero;
-        let mut is_completed: usize = 0;
-        loop {
-            is_completed = 0;
-            for x in 0..reality {
-                if (*list)[x] != 0 {
-                    is_completed += 1;
-                }
-            }
-            if is_completed == reality {
-                break;
-            }
-            for x in 0..list_len - 1 {
-                if (*list)[x] == 0 {
-                    (*list)[x] = (*list)[x + 1];
-                    (*list)[x + 1] = 0;
-                }
-            }
-        }
-        (*self).len = reality;
+        (*self).len = wrt_idx;
     }
 
     
     pub fn split_off(self: &mut Self, spl_off: usize) -> Self {
+        if spl_off > (*self).len || !(*self).as_str().is_char_boundary(spl_off) {
+            panic!("Index out of bounds.");
+        }
         let list: &mut [u8] = unsafe {
             &mut *transmute::<(*mut MaybeUninit<u8>, usize), *mut [u8]>((
                 (*self).list.as_mut_ptr(),
@@ -549,6 +881,21 @@ impl<const N: usize> Yangon<N> {
     }
 
     
+    pub fn split_to_vec<'y, G: yGeneric<'y, C>, const C: usize, const F: usize, const M: usize>(
+        self: &Self,
+        pre: G,
+    ) -> Result<YangonVec<F, M>, yError> {
+        let mut inst: YangonVec<F, M> = YangonVec::new();
+        match pre.iden() {
+            yPattern::Slice(slice) => inst.extend_from((*self).as_str().split(slice))?,
+            yPattern::Char(ch) => inst.extend_from((*self).as_str().split(ch))?,
+            yPattern::CharSlice(ch_slice) => inst.extend_from((*self).as_str().split(&ch_slice[..]))?,
+            yPattern::Closure(closure) => inst.extend_from((*self).as_str().split(closure))?,
+        }
+        Ok(inst)
+    }
+
+    
     #[inline]
     pub fn as_str(self: &Self) -> &str {
         unsafe {
@@ -584,99 +931,24 @@ impl<const N: usize> Yangon<N> {
 
     
     pub fn replace_it(self: &Self, slice: &str, upg: &str) -> Self {
-        let upg_byt: &[u8] = upg.as_bytes();
-        let upg_len: usize = upg_byt.len();
-        let bytes: &[u8] = slice.as_bytes();
-        let byt_len: usize = bytes.len();
-        if byt_len == 0 && upg_len == 0 {
+        if slice.is_empty() && upg.is_empty() {
             return (*self).clone();
         }
-        let mut inst: Yangon<N> = Self::with_capacity();
-        let ist_ptr: *mut u8 = inst.list.as_mut_ptr() as *mut u8;
-        let ptr: *const u8 = (*self).list.as_ptr() as *const u8;
-        let len: usize = (*self).len;
-        let mut ist_idx: usize = 0;
-        let mut idx: usize = 0;
-        if byt_len == 0 && upg_len != 0 {
-            let mut end_idx: usize = 1;
-            let list: &[u8] = unsafe { &*transmute::<(*const u8, usize), *const [u8]>((ptr, len)) };
-            for &x in upg_byt {
-                unsafe {
-                    *ist_ptr.add(ist_idx) = x;
-                    ist_idx += 1;
-                }
-            }
-            if len == 0 {
-                inst.len = ist_idx;
-                return inst;
-            }
-            loop {
-                if end_idx > len {
-                    break;
-                }
-                match str::from_utf8(&(*list)[idx..end_idx]) {
-                    Ok(slicx) => {
-                        for &x in slicx.as_bytes() {
-                            unsafe {
-                                *ist_ptr.add(ist_idx) = x;
-                            }
-                            ist_idx += 1;
-                        }
-                        for &x in upg_byt {
-                            unsafe {
-                                *ist_ptr.add(ist_idx) = x;
-                            }
-                            ist_idx += 1;
-                        }
-                        idx = end_idx;
-                        end_idx += 1;
-                    }
-                    Err(_) => {
-                        end_idx += 1;
-                    }
-                }
-            }
-            inst.len = ist_idx;
-            return inst;
+        let text: &str = (*self).as_str();
+        let hits: usize = text.matches(slice).count();
+        if text.len() - hits * slice.len() + hits * upg.len() > N {
+            panic!("Capacity Overflow.");
         }
-        let frt_byt: u8 = (*bytes)[0];
-        let mut is_match: usize = 0;
-        let mut counter: usize = 0;
+        let mut inst: Yangon<N> = Self::with_capacity();
+        let mut lst_idx: usize = 0;
         unsafe {
-            loop {
-                if idx >= len {
-                    break;
-                }
-                if frt_byt == *ptr.add(idx) {
-                    counter = idx;
-                    for &x in bytes {
-                        if x == *ptr.add(counter) {
-                            is_match += 1;
-                        } else {
-                            break;
-                        }
-                        counter += 1;
-                    }
-                    if is_match == byt_len {
-                        for &x in upg_byt {
-                            *ist_ptr.add(ist_idx) = x;
-                            ist_idx += 1;
-                        }
-                        idx = counter;
-                    } else {
-                        *ist_ptr.add(ist_idx) = *ptr.add(idx);
-                        ist_idx += 1;
-                        idx += 1;
-                    }
-                    is_match = 0;
-                } else {
-                    *ist_ptr.add(ist_idx) = *ptr.add(idx);
-                    ist_idx += 1;
-                    idx += 1;
-                }
+            for (idx, _) in text.match_indices(slice) {
+                inst.push_str_unchecked(&text[lst_idx..idx]);
+                inst.push_str_unchecked(upg);
+                lst_idx = idx + slice.len();
             }
+            inst.push_str_unchecked(&text[lst_idx..]);
         }
-        inst.len = ist_idx;
         inst
     }
 
@@ -705,6 +977,13 @@ impl<const N: usize> Yangon<N> {
                 if len == 0 {
                     return (*self).clone();
                 }
+                let mut new_len: usize = 0;
+                for ch in (*self).as_str().chars() {
+                    new_len += if closure(ch) { upg.len() } else { ch.len_utf8() };
+                }
+                if new_len > N {
+                    panic!("Capacity Overflow.");
+                }
                 let mut inst: Yangon<N> = Self::with_capacity();
                 let upg_byt: &[u8] = upg.as_bytes();
                 let list: &[u8] = unsafe { from_raw_parts((*self).as_ptr(), len) };
@@ -748,6 +1027,22 @@ impl<const N: usize> Yangon<N> {
     }
 
     
+    pub fn replace_cow<'y, G: yGeneric<'y, C>, const C: usize>(self: &Self, pre: G, upg: &str) -> yCow<'_, Self> {
+        let text: &str = (*self).as_str();
+        let unchanged: bool = match pre.iden() {
+            yPattern::Slice(slice) => slice == upg || (!slice.is_empty() && !text.contains(slice)),
+            yPattern::Char(ch) => !text.contains(ch),
+            yPattern::CharSlice(ch_slice) => !text.contains(ch_slice),
+            yPattern::Closure(closure) => !text.contains(closure),
+        };
+        if unchanged {
+            yCow::Borrowed(text)
+        } else {
+            yCow::Owned((*self).replace(pre, upg))
+        }
+    }
+
+    
     #[inline]
     pub unsafe fn list(self: &mut Self) -> &mut [MaybeUninit<u8>] {
         &mut (*self).list
@@ -766,6 +1061,127 @@ impl<const N: usize> Yangon<N> {
     }
 
     
+    pub fn display_width(self: &Self) -> usize {
+        let mut width: usize = 0;
+        for ch in (*self).as_str().chars() {
+            width += width::char_width(ch);
+        }
+        width
+    }
+
+    
+    pub fn truncate_to_width(self: &mut Self, cols: usize, ellipsis: &str) -> Result<(), yError> {
+        if (*self).display_width() <= cols {
+            return Ok(());
+        }
+        let mut ell_width: usize = 0;
+        for ch in ellipsis.chars() {
+            ell_width += width::char_width(ch);
+        }
+        let (limit, ellipsis): (usize, &str) = if ell_width > cols {
+            (cols, "")
+        } else {
+            (cols - ell_width, ellipsis)
+        };
+        let mut cut: usize = 0;
+        let mut width: usize = 0;
+        for (idx, ch) in (*self).as_str().char_indices() {
+            let w: usize = width::char_width(ch);
+            if width + w > limit {
+                break;
+            }
+            width += w;
+            cut = idx + ch.len_utf8();
+        }
+        if cut + ellipsis.len() > (*self).capacity {
+            return Err(yError::CapacityOverflow);
+        }
+        (*self).len = cut;
+        unsafe {
+            (*self).push_str_unchecked(ellipsis);
+        }
+        Ok(())
+    }
+
+    
+    pub fn pad_to_width(self: &mut Self, cols: usize, align: yAlign) -> Result<(), yError> {
+        (*self).pad_with(cols, ' ', align)
+    }
+
+    
+    pub fn center(self: &mut Self, cols: usize, fill: char) -> Result<(), yError> {
+        (*self).pad_with(cols, fill, yAlign::Center)
+    }
+
+    
+    fn pad_with(self: &mut Self, cols: usize, fill: char, align: yAlign) -> Result<(), yError> {
+        let width: usize = (*self).display_width();
+        if width >= cols {
+            return Ok(());
+        }
+        let pad: usize = cols - width;
+        let (lft_cols, rgt_cols): (usize, usize) = match align {
+            yAlign::Left => (0, pad),
+            yAlign::Right => (pad, 0),
+            yAlign::Center => (pad / 2, pad - pad / 2),
+        };
+        let fill: char = if width::char_width(fill) == 0 { ' ' } else { fill };
+        let fil_width: usize = width::char_width(fill);
+        let fil_len: usize = fill.len_utf8();
+        let lft_fill: usize = lft_cols / fil_width;
+        let lft_space: usize = lft_cols % fil_width;
+        let rgt_fill: usize = rgt_cols / fil_width;
+        let rgt_space: usize = rgt_cols % fil_width;
+        let lft_bytes: usize = lft_fill * fil_len + lft_space;
+        let len: usize = (*self).len;
+        if len + lft_bytes + rgt_fill * fil_len + rgt_space > (*self).capacity {
+            return Err(yError::CapacityOverflow);
+        }
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
+        let mut idx: usize = len;
+        while idx > 0 {
+            idx -= 1;
+            unsafe {
+                *ptr.add(idx + lft_bytes) = *ptr.add(idx);
+            }
+        }
+        let mut bind: [u8; 4] = [0, 0, 0, 0];
+        let fil_byt: &[u8] = fill.encode_utf8(&mut bind).as_bytes();
+        let mut idx: usize = 0;
+        for _ in 0..lft_fill {
+            for &x in fil_byt {
+                unsafe {
+                    *ptr.add(idx) = x;
+                }
+                idx += 1;
+            }
+        }
+        for _ in 0..lft_space {
+            unsafe {
+                *ptr.add(idx) = b' ';
+            }
+            idx += 1;
+        }
+        let mut idx: usize = len + lft_bytes;
+        for _ in 0..rgt_fill {
+            for &x in fil_byt {
+                unsafe {
+                    *ptr.add(idx) = x;
+                }
+                idx += 1;
+            }
+        }
+        for _ in 0..rgt_space {
+            unsafe {
+                *ptr.add(idx) = b' ';
+            }
+            idx += 1;
+        }
+        (*self).len = idx;
+        Ok(())
+    }
+
+    
     pub fn from(slice: &str) -> Self {
         let mut inst: Self = Self::with_capacity();
         let mut idx: usize = 0;
@@ -788,6 +1204,65 @@ impl<const N: usize> Yangon<N> {
 }
 
 
+impl<const N: usize> yCow<'_, Yangon<N>> {
+    
+    #[inline]
+    pub fn is_borrowed(self: &Self) -> bool {
+        matches!(self, yCow::Borrowed(_))
+    }
+
+    
+    #[inline]
+    pub fn is_owned(self: &Self) -> bool {
+        matches!(self, yCow::Owned(_))
+    }
+
+    
+    pub fn to_mut(self: &mut Self) -> Result<&mut Yangon<N>, yError> {
+        if let yCow::Borrowed(slice) = *self {
+            if slice.len() > N {
+                return Err(yError::CapacityOverflow);
+            }
+            *self = yCow::Owned(Yangon::from(slice));
+        }
+        match self {
+            yCow::Owned(y) => Ok(y),
+            yCow::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    
+    pub fn into_owned(self: Self) -> Result<Yangon<N>, yError> {
+        match self {
+            yCow::Borrowed(slice) => {
+                if slice.len() > N {
+                    return Err(yError::CapacityOverflow);
+                }
+                Ok(Yangon::from(slice))
+            }
+            yCow::Owned(y) => Ok(y),
+        }
+    }
+
+    
+    pub fn trim_cow(self: Self) -> Self {
+        match self {
+            yCow::Borrowed(slice) => yCow::Borrowed(slice.trim()),
+            yCow::Owned(mut y) => {
+                let text: &str = y.as_str();
+                let lead: usize = text.len() - text.trim_start().len();
+                let keep: usize = text.trim().len();
+                if keep < text.len() {
+                    y.truncate(lead + keep);
+                    y.replace_range(..lead, "");
+                }
+                yCow::Owned(y)
+            }
+        }
+    }
+}
+
+
 #[macro_export]
 macro_rules! yangon {
     ($($str: expr)?) => {{
@@ -928,6 +1403,139 @@ impl<const N: usize> PartialEq<&str> for Yangon<N> {
     }
 }
 
+impl<const N: usize, const M: usize> PartialEq<Yangon<M>> for Yangon<N> {
+    fn eq(self: &Self, other: &Yangon<M>) -> bool {
+        (*self).as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for Yangon<N> {}
+
+impl<const N: usize, const M: usize> PartialOrd<Yangon<M>> for Yangon<N> {
+    fn partial_cmp(self: &Self, other: &Yangon<M>) -> Option<Ordering> {
+        (*self).as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Ord for Yangon<N> {
+    fn cmp(self: &Self, other: &Self) -> Ordering {
+        (*self).as_str().cmp(other.as_str())
+    }
+}
+
+macro_rules! impl_str_cmp {
+    ($($lhs: ty),*) => {
+        $(
+            impl<'a, const N: usize> PartialEq<$lhs> for Yangon<N> {
+                fn eq(self: &Self, other: &$lhs) -> bool {
+                    (*self).as_str() == AsRef::<str>::as_ref(other)
+                }
+            }
+
+            impl<'a, const N: usize> PartialEq<Yangon<N>> for $lhs {
+                fn eq(self: &Self, other: &Yangon<N>) -> bool {
+                    AsRef::<str>::as_ref(self) == other.as_str()
+                }
+            }
+
+            impl<'a, const N: usize> PartialOrd<$lhs> for Yangon<N> {
+                fn partial_cmp(self: &Self, other: &$lhs) -> Option<Ordering> {
+                    (*self).as_str().partial_cmp(AsRef::<str>::as_ref(other))
+                }
+            }
+
+            impl<'a, const N: usize> PartialOrd<Yangon<N>> for $lhs {
+                fn partial_cmp(self: &Self, other: &Yangon<N>) -> Option<Ordering> {
+                    AsRef::<str>::as_ref(self).partial_cmp(other.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_str_cmp!(str, String, Cow<'a, str>);
+
+impl<const N: usize> PartialEq<Yangon<N>> for &str {
+    fn eq(self: &Self, other: &Yangon<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialOrd<&str> for Yangon<N> {
+    fn partial_cmp(self: &Self, other: &&str) -> Option<Ordering> {
+        (*self).as_str().partial_cmp(*other)
+    }
+}
+
+impl<const N: usize> PartialOrd<Yangon<N>> for &str {
+    fn partial_cmp(self: &Self, other: &Yangon<N>) -> Option<Ordering> {
+        (*self).partial_cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for Yangon<N> {
+    fn hash<H: Hasher>(self: &Self, state: &mut H) {
+        (*self).as_str().hash(state)
+    }
+}
+
+impl<const N: usize> Borrow<str> for Yangon<N> {
+    fn borrow(self: &Self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl<const N: usize> Default for Yangon<N> {
+    fn default() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl<const N: usize> FromStr for Yangon<N> {
+    type Err = yError;
+    fn from_str(slice: &str) -> Result<Self, Self::Err> {
+        let mut inst: Self = Self::with_capacity();
+        inst.push_str(slice)?;
+        Ok(inst)
+    }
+}
+
+impl<const N: usize> Extend<char> for Yangon<N> {
+    fn extend<I: IntoIterator<Item = char>>(self: &mut Self, iter: I) {
+        for x in iter {
+            if (*self).push(x).is_err() {
+                panic!("Capacity Overflow.")
+            }
+        }
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a str> for Yangon<N> {
+    fn extend<I: IntoIterator<Item = &'a str>>(self: &mut Self, iter: I) {
+        for x in iter {
+            if (*self).push_str(x).is_err() {
+                panic!("Capacity Overflow.")
+            }
+        }
+    }
+}
+
+impl<const N: usize> Add<&str> for Yangon<N> {
+    type Output = Self;
+    fn add(mut self: Self, slice: &str) -> Self::Output {
+        self += slice;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign<&str> for Yangon<N> {
+    fn add_assign(self: &mut Self, slice: &str) {
+        if (*self).push_str(slice).is_err() {
+            panic!("Capacity Overflow.")
+        }
+    }
+}
+
 impl<const N: usize> AsRef<str> for Yangon<N> {
     fn as_ref(self: &Self) -> &str {
         unsafe {
@@ -973,6 +1581,74 @@ impl<const N: usize> Deref for yCow<'_, Yangon<N>> {
     }
 }
 
+impl<const N: usize> AsRef<str> for yCow<'_, Yangon<N>> {
+    fn as_ref(self: &Self) -> &str {
+        self
+    }
+}
+
+impl<'a, 'b, const N: usize> PartialEq<yCow<'b, Yangon<N>>> for yCow<'a, Yangon<N>> {
+    fn eq(self: &Self, other: &yCow<'b, Yangon<N>>) -> bool {
+        **self == **other
+    }
+}
+
+impl<const N: usize> Eq for yCow<'_, Yangon<N>> {}
+
+impl<const N: usize> PartialEq<str> for yCow<'_, Yangon<N>> {
+    fn eq(self: &Self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for yCow<'_, Yangon<N>> {
+    fn eq(self: &Self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl<const N: usize> PartialEq<Yangon<N>> for yCow<'_, Yangon<N>> {
+    fn eq(self: &Self, other: &Yangon<N>) -> bool {
+        **self == *other.as_str()
+    }
+}
+
+impl<const N: usize> Hash for yCow<'_, Yangon<N>> {
+    fn hash<H: Hasher>(self: &Self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<'c, const N: usize> From<&'c str> for yCow<'c, Yangon<N>> {
+    fn from(slice: &'c str) -> Self {
+        yCow::Borrowed(slice)
+    }
+}
+
+impl<const N: usize> From<Yangon<N>> for yCow<'_, Yangon<N>> {
+    fn from(y: Yangon<N>) -> Self {
+        yCow::Owned(y)
+    }
+}
+
+impl<const N: usize> From<yCow<'_, Yangon<N>>> for String {
+    fn from(cow: yCow<'_, Yangon<N>>) -> Self {
+        String::from(&*cow)
+    }
+}
+
+impl<const N: usize> Display for yCow<'_, Yangon<N>> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<const N: usize> Debug for yCow<'_, Yangon<N>> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&**self, f)
+    }
+}
+
 impl<const N: usize> Display for Yangon<N> {
     fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", unsafe {
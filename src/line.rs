@@ -0,0 +1,147 @@
+use crate::{yError, yTerminator, Utf8Appender, Yangon};
+
+
+impl<'t> yTerminator<'t> {
+    
+    #[inline]
+    pub fn as_str(self: &Self) -> &'t str {
+        match *self {
+            yTerminator::Lf => "\n",
+            yTerminator::CrLf => "\r\n",
+            yTerminator::Custom(slice) => slice,
+        }
+    }
+}
+
+
+pub struct LineBuffer<'t, const N: usize> {
+    buf: Yangon<N>,
+    pending: [u8; 4],
+    pending_len: usize,
+    consumed: usize,
+    full: bool,
+    skipping: bool,
+    terminator: yTerminator<'t>,
+}
+
+
+impl<'t, const N: usize> LineBuffer<'t, N> {
+    
+    pub fn new() -> Self {
+        Self::with_terminator(yTerminator::Lf)
+    }
+
+    
+    pub fn with_terminator(terminator: yTerminator<'t>) -> Self {
+        if terminator.as_str().is_empty() {
+            panic!("Empty terminator.");
+        }
+        Self {
+            buf: Yangon::with_capacity(),
+            pending: [0, 0, 0, 0],
+            pending_len: 0,
+            consumed: 0,
+            full: false,
+            skipping: false,
+            terminator,
+        }
+    }
+
+    
+    #[inline]
+    pub fn terminator(self: &Self) -> yTerminator<'t> {
+        (*self).terminator
+    }
+
+    
+    pub fn push_bytes(self: &mut Self, chunk: &[u8]) -> Result<usize, yError> {
+        if (*self).consumed > 0 {
+            (*self).buf.replace_range(..(*self).consumed, "");
+            (*self).consumed = 0;
+        }
+        let mut app: Utf8Appender<'_, N> = Utf8Appender::new(&mut (*self).buf);
+        app.push_bytes(&(*self).pending[..(*self).pending_len])?;
+        let byt_len: usize = app.push_prefix(chunk)?;
+        let pending: &[u8] = app.pending();
+        (*self).pending[..pending.len()].copy_from_slice(pending);
+        (*self).pending_len = pending.len();
+        (*self).full = byt_len < chunk.len();
+        Ok(byt_len)
+    }
+
+    
+    fn discard(self: &mut Self) {
+        let term: &str = (*self).terminator.as_str();
+        let rest: &str = &(*self).buf.as_str()[(*self).consumed..];
+        let mut keep: usize = term.len() - 1;
+        while keep > 0 && !(term.is_char_boundary(keep) && rest.ends_with(&term[..keep])) {
+            keep -= 1;
+        }
+        (*self).consumed = (*self).buf.len() - keep;
+    }
+
+    
+    pub fn next_line(self: &mut Self) -> Result<Option<&str>, yError> {
+        let term: &str = (*self).terminator.as_str();
+        loop {
+            let srt_idx: usize = (*self).consumed;
+            match (*self).buf.as_str()[srt_idx..].find(term) {
+                Some(idx) => {
+                    (*self).consumed += idx + term.len();
+                    if (*self).skipping {
+                        (*self).skipping = false;
+                        continue;
+                    }
+                    return Ok(Some(&(*self).buf.as_str()[srt_idx..srt_idx + idx]));
+                }
+                None => {
+                    if (*self).skipping {
+                        (*self).discard();
+                        return Ok(None);
+                    }
+                    if (*self).full && srt_idx == 0 {
+                        (*self).full = false;
+                        (*self).skipping = true;
+                        (*self).discard();
+                        return Err(yError::CapacityOverflow);
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    
+    #[inline]
+    pub fn remainder(self: &Self) -> &str {
+        &(*self).buf.as_str()[(*self).consumed..]
+    }
+
+    
+    #[inline]
+    pub fn pending(self: &Self) -> &[u8] {
+        &(*self).pending[..(*self).pending_len]
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        N
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).buf.clear();
+        (*self).pending_len = 0;
+        (*self).consumed = 0;
+        (*self).full = false;
+        (*self).skipping = false;
+    }
+}
+
+
+impl<const N: usize> Default for LineBuffer<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
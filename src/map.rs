@@ -0,0 +1,353 @@
+use crate::{interner::fnv1a, yError, Yangon};
+use std::{
+    array,
+    fmt::{Debug, Formatter, Result as FmtResult},
+};
+
+
+#[derive(Clone)]
+pub struct YangonMap<const K_CAP: usize, V, const SLOTS: usize> {
+    slots: [Option<(Yangon<K_CAP>, V)>; SLOTS],
+    len: usize,
+}
+
+
+impl<const K_CAP: usize, V, const SLOTS: usize> YangonMap<K_CAP, V, SLOTS> {
+    
+    const HAS_SLOTS: () = assert!(SLOTS > 0, "YangonMap needs at least one slot");
+
+    
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::HAS_SLOTS;
+        Self {
+            slots: array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    
+    #[inline]
+    fn home(key: &str) -> usize {
+        (fnv1a(key.as_bytes()) % SLOTS as u64) as usize
+    }
+
+    
+    #[inline]
+    fn next(idx: usize) -> usize {
+        if idx + 1 == SLOTS {
+            0
+        } else {
+            idx + 1
+        }
+    }
+
+    
+    fn find(self: &Self, key: &str) -> Result<usize, Option<usize>> {
+        let mut idx: usize = Self::home(key);
+        for _ in 0..SLOTS {
+            match &(*self).slots[idx] {
+                None => return Err(Some(idx)),
+                Some((k, _)) if k.as_str() == key => return Ok(idx),
+                Some(_) => idx = Self::next(idx),
+            }
+        }
+        Err(None)
+    }
+
+    
+    pub fn insert(self: &mut Self, key: &str, value: V) -> Result<Option<V>, yError> {
+        match (*self).find(key) {
+            Ok(idx) => match &mut (*self).slots[idx] {
+                Some((_, v)) => Ok(Some(std::mem::replace(v, value))),
+                None => unreachable!(),
+            },
+            Err(Some(idx)) => {
+                if key.len() > K_CAP {
+                    return Err(yError::CapacityOverflow);
+                }
+                (*self).slots[idx] = Some((Yangon::from(key), value));
+                (*self).len += 1;
+                Ok(None)
+            }
+            Err(None) => Err(yError::CapacityOverflow),
+        }
+    }
+
+    
+    pub fn get(self: &Self, key: &str) -> Option<&V> {
+        let idx: usize = (*self).find(key).ok()?;
+        (*self).slots[idx].as_ref().map(|(_, v)| v)
+    }
+
+    
+    pub fn get_mut(self: &mut Self, key: &str) -> Option<&mut V> {
+        let idx: usize = (*self).find(key).ok()?;
+        (*self).slots[idx].as_mut().map(|(_, v)| v)
+    }
+
+    
+    #[inline]
+    pub fn contains_key(self: &Self, key: &str) -> bool {
+        (*self).find(key).is_ok()
+    }
+
+    
+    pub fn remove(self: &mut Self, key: &str) -> Option<V> {
+        let idx: usize = (*self).find(key).ok()?;
+        let (_, value): (Yangon<K_CAP>, V) = (*self).slots[idx].take()?;
+        (*self).len -= 1;
+        let mut hole: usize = idx;
+        let mut nxt_idx: usize = Self::next(idx);
+        while let Some((k, _)) = &(*self).slots[nxt_idx] {
+            let home: usize = Self::home(k.as_str());
+            let stays: bool = if hole <= nxt_idx {
+                hole < home && home <= nxt_idx
+            } else {
+                hole < home || home <= nxt_idx
+            };
+            if !stays {
+                (*self).slots[hole] = (*self).slots[nxt_idx].take();
+                hole = nxt_idx;
+            }
+            nxt_idx = Self::next(nxt_idx);
+        }
+        Some(value)
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        SLOTS
+    }
+
+    
+    pub fn iter(self: &Self) -> impl Iterator<Item = (&str, &V)> + '_ {
+        (*self).slots.iter().filter_map(|x| x.as_ref().map(|(k, v)| (k.as_str(), v)))
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        for x in (*self).slots.iter_mut() {
+            *x = None;
+        }
+        (*self).len = 0;
+    }
+}
+
+
+impl<const K_CAP: usize, V, const SLOTS: usize> Default for YangonMap<K_CAP, V, SLOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const K_CAP: usize, V: Debug, const SLOTS: usize> Debug for YangonMap<K_CAP, V, SLOTS> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries((*self).iter()).finish()
+    }
+}
+
+
+#[derive(Clone)]
+pub struct SortedYangonMap<const K_CAP: usize, V, const SLOTS: usize> {
+    entries: [Option<(Yangon<K_CAP>, V)>; SLOTS],
+    len: usize,
+}
+
+
+impl<const K_CAP: usize, V, const SLOTS: usize> SortedYangonMap<K_CAP, V, SLOTS> {
+    
+    pub fn new() -> Self {
+        Self {
+            entries: array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    
+    fn find(self: &Self, key: &str) -> Result<usize, usize> {
+        (*self).entries[..(*self).len].binary_search_by(|x| match x {
+            Some((k, _)) => k.as_str().cmp(key),
+            None => unreachable!(),
+        })
+    }
+
+    
+    pub fn insert(self: &mut Self, key: &str, value: V) -> Result<Option<V>, yError> {
+        match (*self).find(key) {
+            Ok(idx) => match &mut (*self).entries[idx] {
+                Some((_, v)) => Ok(Some(std::mem::replace(v, value))),
+                None => unreachable!(),
+            },
+            Err(idx) => {
+                let len: usize = (*self).len;
+                if len == SLOTS || key.len() > K_CAP {
+                    return Err(yError::CapacityOverflow);
+                }
+                (*self).entries[len] = Some((Yangon::from(key), value));
+                (*self).entries[idx..=len].rotate_right(1);
+                (*self).len += 1;
+                Ok(None)
+            }
+        }
+    }
+
+    
+    pub fn get(self: &Self, key: &str) -> Option<&V> {
+        let idx: usize = (*self).find(key).ok()?;
+        (*self).entries[idx].as_ref().map(|(_, v)| v)
+    }
+
+    
+    pub fn get_mut(self: &mut Self, key: &str) -> Option<&mut V> {
+        let idx: usize = (*self).find(key).ok()?;
+        (*self).entries[idx].as_mut().map(|(_, v)| v)
+    }
+
+    
+    #[inline]
+    pub fn contains_key(self: &Self, key: &str) -> bool {
+        (*self).find(key).is_ok()
+    }
+
+    
+    pub fn remove(self: &mut Self, key: &str) -> Option<V> {
+        let idx: usize = (*self).find(key).ok()?;
+        let len: usize = (*self).len;
+        (*self).entries[idx..len].rotate_left(1);
+        (*self).len -= 1;
+        (*self).entries[len - 1].take().map(|(_, v)| v)
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        SLOTS
+    }
+
+    
+    pub fn iter(self: &Self) -> impl Iterator<Item = (&str, &V)> + '_ {
+        (*self).entries[..(*self).len].iter().filter_map(|x| x.as_ref().map(|(k, v)| (k.as_str(), v)))
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        for x in (*self).entries[..(*self).len].iter_mut() {
+            *x = None;
+        }
+        (*self).len = 0;
+    }
+}
+
+
+impl<const K_CAP: usize, V, const SLOTS: usize> Default for SortedYangonMap<K_CAP, V, SLOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const K_CAP: usize, V: Debug, const SLOTS: usize> Debug for SortedYangonMap<K_CAP, V, SLOTS> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_map().entries((*self).iter()).finish()
+    }
+}
+
+
+macro_rules! impl_set {
+    ($set: ident, $map: ident) => {
+        #[derive(Clone, Default)]
+        pub struct $set<const K_CAP: usize, const SLOTS: usize> {
+            map: $map<K_CAP, (), SLOTS>,
+        }
+
+        impl<const K_CAP: usize, const SLOTS: usize> $set<K_CAP, SLOTS> {
+            
+            pub fn new() -> Self {
+                Self { map: $map::new() }
+            }
+
+            
+            pub fn insert(self: &mut Self, key: &str) -> Result<bool, yError> {
+                Ok((*self).map.insert(key, ())?.is_none())
+            }
+
+            
+            #[inline]
+            pub fn contains(self: &Self, key: &str) -> bool {
+                (*self).map.contains_key(key)
+            }
+
+            
+            #[inline]
+            pub fn remove(self: &mut Self, key: &str) -> bool {
+                (*self).map.remove(key).is_some()
+            }
+
+            
+            #[inline]
+            pub fn len(self: &Self) -> usize {
+                (*self).map.len()
+            }
+
+            
+            #[inline]
+            pub fn is_empty(self: &Self) -> bool {
+                (*self).map.is_empty()
+            }
+
+            
+            #[inline]
+            pub fn capacity(self: &Self) -> usize {
+                SLOTS
+            }
+
+            
+            pub fn iter(self: &Self) -> impl Iterator<Item = &str> + '_ {
+                (*self).map.iter().map(|(k, _)| k)
+            }
+
+            
+            #[inline]
+            pub fn clear(self: &mut Self) {
+                (*self).map.clear()
+            }
+        }
+
+        impl<const K_CAP: usize, const SLOTS: usize> Debug for $set<K_CAP, SLOTS> {
+            fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+                f.debug_set().entries((*self).iter()).finish()
+            }
+        }
+    };
+}
+
+
+impl_set!(YangonSet, YangonMap);
+impl_set!(SortedYangonSet, SortedYangonMap);
@@ -0,0 +1,145 @@
+use crate::{yError, Yangon};
+use std::{
+    ffi::CStr,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::Deref,
+    slice::from_raw_parts,
+    str::FromStr,
+};
+
+
+#[derive(Clone)]
+pub struct NulYangon<const N: usize = 10240> {
+    inner: Yangon<N>,
+}
+
+
+impl<const N: usize> NulYangon<N> {
+    
+    const HAS_ROOM: () = assert!(N > 0, "NulYangon needs room for the terminator");
+
+    
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::HAS_ROOM;
+        let mut inner: Yangon<N> = Yangon::with_capacity();
+        unsafe {
+            inner.set_cap(N.saturating_sub(1));
+        }
+        let mut inst: Self = Self { inner };
+        inst.terminate();
+        inst
+    }
+
+    
+    fn terminate(self: &mut Self) {
+        let len: usize = (*self).inner.len();
+        if len < N {
+            unsafe {
+                *(*self).inner.as_mut_ptr().add(len) = 0;
+            }
+        }
+    }
+
+    
+    pub fn push_str(self: &mut Self, slice: &str) -> Result<(), yError> {
+        if slice.as_bytes().contains(&0) {
+            return Err(yError::NulError);
+        }
+        (*self).inner.push_str(slice)?;
+        (*self).terminate();
+        Ok(())
+    }
+
+    
+    pub fn push(self: &mut Self, ch: char) -> Result<(), yError> {
+        if ch == '\0' {
+            return Err(yError::NulError);
+        }
+        (*self).inner.push(ch)?;
+        (*self).terminate();
+        Ok(())
+    }
+
+    
+    pub fn pop(self: &mut Self) -> Option<char> {
+        let ch: Option<char> = (*self).inner.pop();
+        (*self).terminate();
+        ch
+    }
+
+    
+    pub fn truncate(self: &mut Self, t_cate: usize) {
+        (*self).inner.truncate(t_cate);
+        (*self).terminate();
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).inner.clear();
+        (*self).terminate();
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        (*self).inner.capacity()
+    }
+
+    
+    #[inline]
+    pub fn as_yangon(self: &Self) -> &Yangon<N> {
+        &(*self).inner
+    }
+
+    
+    pub fn as_c_str(self: &Self) -> &CStr {
+        let bytes: &[u8] = unsafe { from_raw_parts((*self).inner.as_ptr(), (*self).inner.len() + 1) };
+        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    }
+
+    
+    #[inline]
+    pub fn as_ptr(self: &Self) -> *const u8 {
+        (*self).inner.as_ptr()
+    }
+}
+
+
+impl<const N: usize> Default for NulYangon<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize> FromStr for NulYangon<N> {
+    type Err = yError;
+    fn from_str(slice: &str) -> Result<Self, Self::Err> {
+        let mut inst: Self = Self::new();
+        inst.push_str(slice)?;
+        Ok(inst)
+    }
+}
+
+
+impl<const N: usize> Deref for NulYangon<N> {
+    type Target = str;
+    fn deref(self: &Self) -> &Self::Target {
+        (*self).inner.as_str()
+    }
+}
+
+
+impl<const N: usize> Display for NulYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&(*self).inner, f)
+    }
+}
+
+
+impl<const N: usize> Debug for NulYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&(*self).inner, f)
+    }
+}
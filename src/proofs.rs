@@ -0,0 +1,109 @@
+use crate::Yangon;
+use std::str;
+
+
+const CAP: usize = 8;
+
+
+fn any_yangon() -> Yangon<CAP> {
+    let mut inst: Yangon<CAP> = Yangon::with_capacity();
+    let count: usize = kani::any();
+    kani::assume(count <= 2);
+    for _ in 0..count {
+        let ch: char = kani::any();
+        let _ = inst.push(ch);
+    }
+    inst
+}
+
+
+fn check(yangon: &Yangon<CAP>) {
+    assert!(yangon.len() <= yangon.capacity());
+    assert!(yangon.capacity() <= CAP);
+    assert!(str::from_utf8(yangon.as_bytes()).is_ok());
+    assert_eq!(yangon.as_str().len(), yangon.len());
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn push_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let ch: char = kani::any();
+    let fits: bool = inst.len() + ch.len_utf8() <= inst.capacity();
+    assert_eq!(inst.push(ch).is_ok(), fits);
+    check(&inst);
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn push_str_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let ch: char = kani::any();
+    let mut bind: [u8; 4] = [0; 4];
+    let slice: &str = ch.encode_utf8(&mut bind);
+    let fits: bool = inst.len() + slice.len() <= inst.capacity();
+    assert_eq!(inst.push_str(slice).is_ok(), fits);
+    check(&inst);
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn insert_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let idx: usize = kani::any();
+    let ch: char = kani::any();
+    kani::assume(idx <= inst.len() && inst.as_str().is_char_boundary(idx));
+    kani::assume(inst.len() + ch.len_utf8() <= inst.capacity());
+    inst.insert(idx, ch);
+    assert_eq!(inst.as_str()[idx..].chars().next(), Some(ch));
+    check(&inst);
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn remove_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let idx: usize = kani::any();
+    kani::assume(idx < inst.len() && inst.as_str().is_char_boundary(idx));
+    let expected: char = inst.as_str()[idx..].chars().next().unwrap();
+    assert_eq!(inst.remove(idx), expected);
+    check(&inst);
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn split_off_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let len: usize = inst.len();
+    let idx: usize = kani::any();
+    kani::assume(idx <= len && inst.as_str().is_char_boundary(idx));
+    let tail: Yangon<CAP> = inst.split_off(idx);
+    assert_eq!(inst.len(), idx);
+    assert_eq!(tail.len(), len - idx);
+    check(&inst);
+    check(&tail);
+}
+
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn replace_range_stays_in_bounds() {
+    let mut inst: Yangon<CAP> = any_yangon();
+    let srt_idx: usize = kani::any();
+    let end_idx: usize = kani::any();
+    kani::assume(srt_idx <= end_idx && end_idx <= inst.len());
+    kani::assume(inst.as_str().is_char_boundary(srt_idx) && inst.as_str().is_char_boundary(end_idx));
+    let ch: char = kani::any();
+    let mut bind: [u8; 4] = [0; 4];
+    let slice: &str = ch.encode_utf8(&mut bind);
+    let len: usize = inst.len() - (end_idx - srt_idx) + slice.len();
+    kani::assume(len <= inst.capacity());
+    inst.replace_range(srt_idx..end_idx, slice);
+    assert_eq!(inst.len(), len);
+    check(&inst);
+}
@@ -0,0 +1,36 @@
+use crate::Yangon;
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::vec,
+    strategy::{BoxedStrategy, Strategy},
+    string::{string_regex, Error},
+};
+
+
+#[allow(clippy::result_large_err)]
+pub fn string_of<const N: usize>(regex: &str) -> Result<BoxedStrategy<Yangon<N>>, Error> {
+    Ok(string_regex(regex)?
+        .prop_filter(format!("string longer than {} bytes", N), |slice| slice.len() <= N)
+        .prop_map(|slice| Yangon::from(slice.as_str()))
+        .boxed())
+}
+
+
+impl<const N: usize> Arbitrary for Yangon<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<char>(), 0..=N)
+            .prop_map(|list| {
+                let mut inst: Yangon<N> = Yangon::with_capacity();
+                for ch in list {
+                    if inst.push(ch).is_err() {
+                        break;
+                    }
+                }
+                inst
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,213 @@
+use crate::yEvict;
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    str::from_utf8_unchecked,
+};
+
+
+#[derive(Clone)]
+pub struct RingYangon<const N: usize> {
+    list: [u8; N],
+    head: usize,
+    tail: usize,
+    end: usize,
+    len: usize,
+    wrapped: bool,
+    evict: yEvict,
+}
+
+
+impl<const N: usize> RingYangon<N> {
+    
+    pub const fn new() -> Self {
+        Self::with_eviction(yEvict::Char)
+    }
+
+    
+    pub const fn with_eviction(evict: yEvict) -> Self {
+        Self {
+            list: [0; N],
+            head: 0,
+            tail: 0,
+            end: 0,
+            len: 0,
+            wrapped: false,
+            evict,
+        }
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        N
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).head = 0;
+        (*self).tail = 0;
+        (*self).end = 0;
+        (*self).len = 0;
+        (*self).wrapped = false;
+    }
+
+    
+    fn reserve(self: &mut Self, byt_len: usize) -> Option<usize> {
+        if (*self).len == 0 {
+            (*self).clear();
+        }
+        if (*self).wrapped {
+            if (*self).head - (*self).tail >= byt_len {
+                return Some((*self).tail);
+            }
+        } else if N - (*self).tail >= byt_len {
+            return Some((*self).tail);
+        } else if (*self).head >= byt_len {
+            (*self).end = (*self).tail;
+            (*self).wrapped = true;
+            return Some(0);
+        }
+        None
+    }
+
+    
+    fn evict_char(self: &mut Self) -> u8 {
+        let head: usize = (*self).head;
+        let lead: u8 = (*self).list[head];
+        let byt_len: usize = if lead < 0x80 {
+            1
+        } else if lead < 0xE0 {
+            2
+        } else if lead < 0xF0 {
+            3
+        } else {
+            4
+        };
+        (*self).head += byt_len;
+        (*self).len -= byt_len;
+        if (*self).wrapped && (*self).head == (*self).end {
+            (*self).head = 0;
+            (*self).wrapped = false;
+        }
+        if (*self).len == 0 {
+            (*self).clear();
+        }
+        lead
+    }
+
+    
+    fn evict(self: &mut Self) {
+        match (*self).evict {
+            yEvict::Char => {
+                (*self).evict_char();
+            }
+            yEvict::Line => {
+                while !(*self).is_empty() && (*self).evict_char() != b'\n' {}
+            }
+        }
+    }
+
+    
+    pub fn push(self: &mut Self, ch: char) {
+        let byt_len: usize = ch.len_utf8();
+        if byt_len > N {
+            return;
+        }
+        let idx: usize = loop {
+            match (*self).reserve(byt_len) {
+                Some(idx) => break idx,
+                None => (*self).evict(),
+            }
+        };
+        ch.encode_utf8(&mut (*self).list[idx..idx + byt_len]);
+        (*self).tail = idx + byt_len;
+        (*self).len += byt_len;
+    }
+
+    
+    pub fn push_str(self: &mut Self, slice: &str) {
+        for ch in slice.chars() {
+            (*self).push(ch);
+        }
+    }
+
+    
+    pub fn as_slices(self: &Self) -> (&str, &str) {
+        let list: &[u8] = &(*self).list;
+        unsafe {
+            if (*self).wrapped {
+                (
+                    from_utf8_unchecked(&list[(*self).head..(*self).end]),
+                    from_utf8_unchecked(&list[..(*self).tail]),
+                )
+            } else {
+                (from_utf8_unchecked(&list[(*self).head..(*self).tail]), "")
+            }
+        }
+    }
+
+    
+    pub fn make_contiguous(self: &mut Self) -> &str {
+        if (*self).wrapped {
+            let (head, end): (usize, usize) = ((*self).head, (*self).end);
+            (*self).list[..end].rotate_left(head);
+        } else {
+            let (head, tail): (usize, usize) = ((*self).head, (*self).tail);
+            (*self).list.copy_within(head..tail, 0);
+        }
+        (*self).head = 0;
+        (*self).tail = (*self).len;
+        (*self).end = 0;
+        (*self).wrapped = false;
+        unsafe { from_utf8_unchecked(&(*self).list[..(*self).len]) }
+    }
+
+    
+    pub fn chars(self: &Self) -> impl Iterator<Item = char> + '_ {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        frt.chars().chain(lst.chars())
+    }
+}
+
+
+impl<const N: usize> Default for RingYangon<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize> Write for RingYangon<N> {
+    fn write_str(self: &mut Self, slice: &str) -> FmtResult {
+        (*self).push_str(slice);
+        Ok(())
+    }
+}
+
+
+impl<const N: usize> Display for RingYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        let (frt, lst): (&str, &str) = (*self).as_slices();
+        f.write_str(frt)?;
+        f.write_str(lst)
+    }
+}
+
+
+impl<const N: usize> Debug for RingYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&(*self).to_string(), f)
+    }
+}
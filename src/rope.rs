@@ -0,0 +1,371 @@
+use crate::{yError, Yangon};
+use std::{
+    array,
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    ops::{Bound, RangeBounds},
+};
+
+
+fn byte_of(slice: &str, char_idx: usize) -> usize {
+    match slice.char_indices().nth(char_idx) {
+        Some((idx, _)) => idx,
+        None => slice.len(),
+    }
+}
+
+
+#[derive(Clone)]
+pub struct YangonRope<const CHUNK: usize, const CHUNKS: usize> {
+    chunks: [Yangon<CHUNK>; CHUNKS],
+    chars: [usize; CHUNKS],
+    lines: [usize; CHUNKS],
+    order: [usize; CHUNKS],
+    count: usize,
+    len: usize,
+}
+
+
+impl<const CHUNK: usize, const CHUNKS: usize> YangonRope<CHUNK, CHUNKS> {
+    
+    pub fn new() -> Self {
+        Self {
+            chunks: array::from_fn(|_| Yangon::with_capacity()),
+            chars: [0; CHUNKS],
+            lines: [0; CHUNKS],
+            order: array::from_fn(|idx| idx),
+            count: 0,
+            len: 0,
+        }
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    pub fn char_len(self: &Self) -> usize {
+        (*self).order[..(*self).count].iter().map(|&slot| (*self).chars[slot]).sum()
+    }
+
+    
+    pub fn line_count(self: &Self) -> usize {
+        1 + (*self).order[..(*self).count].iter().map(|&slot| (*self).lines[slot]).sum::<usize>()
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        CHUNK * CHUNKS
+    }
+
+    
+    #[inline]
+    pub fn chunk_count(self: &Self) -> usize {
+        (*self).count
+    }
+
+    
+    pub fn chunks(self: &Self) -> impl Iterator<Item = &str> + '_ {
+        (*self).order[..(*self).count].iter().map(|&slot| (*self).chunks[slot].as_str())
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        (*self).count = 0;
+        (*self).len = 0;
+    }
+
+    
+    fn refresh(self: &mut Self, slot: usize) {
+        let slice: &str = (*self).chunks[slot].as_str();
+        (*self).chars[slot] = slice.chars().count();
+        (*self).lines[slot] = slice.bytes().filter(|&x| x == b'\n').count();
+    }
+
+    
+    fn locate(self: &Self, char_idx: usize) -> (usize, usize) {
+        let mut rest: usize = char_idx;
+        for idx in 0..(*self).count {
+            let slot: usize = (*self).order[idx];
+            if rest <= (*self).chars[slot] {
+                return (idx, byte_of((*self).chunks[slot].as_str(), rest));
+            }
+            rest -= (*self).chars[slot];
+        }
+        panic!("Index out of bounds.");
+    }
+
+    
+    fn insert_chunk(self: &mut Self, idx: usize) -> usize {
+        let count: usize = (*self).count;
+        let slot: usize = (*self).order[count];
+        (*self).order[idx..=count].rotate_right(1);
+        (*self).count += 1;
+        (*self).chunks[slot].clear();
+        (*self).refresh(slot);
+        slot
+    }
+
+    
+    fn remove_chunk(self: &mut Self, idx: usize) {
+        let count: usize = (*self).count;
+        (*self).order[idx..count].rotate_left(1);
+        (*self).count -= 1;
+    }
+
+    
+    pub fn insert(self: &mut Self, char_idx: usize, slice: &str) -> Result<(), yError> {
+        if (*self).count == 0 {
+            if char_idx != 0 {
+                panic!("Index out of bounds.");
+            }
+            if slice.is_empty() {
+                return Ok(());
+            }
+            if CHUNKS == 0 {
+                return Err(yError::CapacityOverflow);
+            }
+            (*self).insert_chunk(0);
+        }
+        let (idx, off): (usize, usize) = (*self).locate(char_idx);
+        let slot: usize = (*self).order[idx];
+        if (*self).chunks[slot].len() + slice.len() <= CHUNK {
+            (*self).chunks[slot].replace_range(off..off, slice);
+            (*self).refresh(slot);
+            (*self).len += slice.len();
+            return Ok(());
+        }
+        let tail: Yangon<CHUNK> = Yangon::from(&(*self).chunks[slot].as_str()[off..]);
+        let mut need: usize = 0;
+        let mut cur: usize = off;
+        for ch in slice.chars().chain(tail.as_str().chars()) {
+            let byt_len: usize = ch.len_utf8();
+            if byt_len > CHUNK {
+                return Err(yError::CapacityOverflow);
+            }
+            if cur + byt_len > CHUNK {
+                need += 1;
+                cur = 0;
+            }
+            cur += byt_len;
+        }
+        if need > CHUNKS - (*self).count {
+            return Err(yError::CapacityOverflow);
+        }
+        (*self).chunks[slot].truncate(off);
+        let mut cur_idx: usize = idx;
+        let mut cur_slot: usize = slot;
+        for ch in slice.chars().chain(tail.as_str().chars()) {
+            if (*self).chunks[cur_slot].push(ch).is_err() {
+                (*self).refresh(cur_slot);
+                cur_idx += 1;
+                cur_slot = (*self).insert_chunk(cur_idx);
+                let _ = (*self).chunks[cur_slot].push(ch);
+            }
+        }
+        (*self).refresh(cur_slot);
+        (*self).len += slice.len();
+        Ok(())
+    }
+
+    
+    #[inline]
+    pub fn push_str(self: &mut Self, slice: &str) -> Result<(), yError> {
+        let char_len: usize = (*self).char_len();
+        (*self).insert(char_len, slice)
+    }
+
+    
+    pub fn delete<R>(self: &mut Self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let char_len: usize = (*self).char_len();
+        let srt_idx: usize = match range.start_bound() {
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x + 1,
+            Bound::Unbounded => 0,
+        };
+        let end_idx: usize = match range.end_bound() {
+            Bound::Included(&x) => x + 1,
+            Bound::Excluded(&x) => x,
+            Bound::Unbounded => char_len,
+        };
+        if srt_idx > end_idx || end_idx > char_len {
+            panic!("Index out of bounds.");
+        }
+        if srt_idx == end_idx {
+            return;
+        }
+        let (frt_idx, frt_off): (usize, usize) = (*self).locate(srt_idx);
+        let (lst_idx, lst_off): (usize, usize) = (*self).locate(end_idx);
+        let frt_slot: usize = (*self).order[frt_idx];
+        let lst_slot: usize = (*self).order[lst_idx];
+        if frt_idx == lst_idx {
+            (*self).chunks[frt_slot].replace_range(frt_off..lst_off, "");
+            (*self).len -= lst_off - frt_off;
+            (*self).refresh(frt_slot);
+        } else {
+            (*self).len -= (*self).chunks[frt_slot].len() - frt_off + lst_off;
+            (*self).chunks[frt_slot].truncate(frt_off);
+            (*self).chunks[lst_slot].replace_range(..lst_off, "");
+            (*self).refresh(frt_slot);
+            (*self).refresh(lst_slot);
+            for _ in frt_idx + 1..lst_idx {
+                let slot: usize = (*self).order[frt_idx + 1];
+                (*self).len -= (*self).chunks[slot].len();
+                (*self).remove_chunk(frt_idx + 1);
+            }
+        }
+        (*self).tidy(frt_idx);
+    }
+
+    
+    fn merge(self: &mut Self, idx: usize) -> bool {
+        if idx + 1 >= (*self).count {
+            return false;
+        }
+        let (slot, next): (usize, usize) = ((*self).order[idx], (*self).order[idx + 1]);
+        if (*self).chunks[slot].len() + (*self).chunks[next].len() > CHUNK {
+            return false;
+        }
+        let moved: Yangon<CHUNK> = (*self).chunks[next].clone();
+        let _ = (*self).chunks[slot].push_str(moved.as_str());
+        (*self).refresh(slot);
+        (*self).remove_chunk(idx + 1);
+        true
+    }
+
+    
+    fn tidy(self: &mut Self, idx: usize) {
+        (*self).merge(idx);
+        if idx > 0 {
+            (*self).merge(idx - 1);
+        }
+        if (*self).count == 1 && (*self).len == 0 {
+            (*self).count = 0;
+        }
+    }
+
+    
+    pub fn offset_to_line_col(self: &Self, offset: usize) -> Option<(usize, usize)> {
+        if offset > (*self).len {
+            return None;
+        }
+        let mut line: usize = 0;
+        let mut col: usize = 0;
+        let mut base: usize = 0;
+        for &slot in &(*self).order[..(*self).count] {
+            let slice: &str = (*self).chunks[slot].as_str();
+            let part: &str = if offset <= base + slice.len() {
+                slice.get(..offset - base)?
+            } else {
+                slice
+            };
+            match part.rfind('\n') {
+                Some(idx) => {
+                    line += part.bytes().filter(|&x| x == b'\n').count();
+                    col = part[idx + 1..].chars().count();
+                }
+                None => col += part.chars().count(),
+            }
+            if part.len() < slice.len() || offset == base + slice.len() {
+                return Some((line, col));
+            }
+            base += slice.len();
+        }
+        Some((line, col))
+    }
+
+    
+    pub fn line_col_to_offset(self: &Self, line: usize, col: usize) -> Option<usize> {
+        let count: usize = (*self).count;
+        let mut base: usize = 0;
+        let mut idx: usize = 0;
+        let mut pos: usize = 0;
+        let mut rest: usize = line;
+        while rest > 0 {
+            if idx == count {
+                return None;
+            }
+            let slot: usize = (*self).order[idx];
+            if (*self).lines[slot] >= rest {
+                pos = (*self).chunks[slot].as_str().match_indices('\n').nth(rest - 1)?.0 + 1;
+                break;
+            }
+            rest -= (*self).lines[slot];
+            base += (*self).chunks[slot].len();
+            idx += 1;
+        }
+        let mut col_left: usize = col;
+        while idx < count {
+            let slice: &str = (*self).chunks[(*self).order[idx]].as_str();
+            for (byt_idx, ch) in slice[pos..].char_indices() {
+                if col_left == 0 {
+                    return Some(base + pos + byt_idx);
+                }
+                if ch == '\n' {
+                    return None;
+                }
+                col_left -= 1;
+            }
+            base += slice.len();
+            pos = 0;
+            idx += 1;
+        }
+        if col_left == 0 {
+            Some(base)
+        } else {
+            None
+        }
+    }
+
+    
+    pub fn to_yangon<const M: usize>(self: &Self) -> Result<Yangon<M>, yError> {
+        let mut inst: Yangon<M> = Yangon::with_capacity();
+        for x in (*self).chunks() {
+            inst.push_str(x)?;
+        }
+        Ok(inst)
+    }
+}
+
+
+impl<const CHUNK: usize, const CHUNKS: usize> Default for YangonRope<CHUNK, CHUNKS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const CHUNK: usize, const CHUNKS: usize> Display for YangonRope<CHUNK, CHUNKS> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        for x in (*self).chunks() {
+            f.write_str(x)?;
+        }
+        Ok(())
+    }
+}
+
+
+impl<const CHUNK: usize, const CHUNKS: usize> Debug for YangonRope<CHUNK, CHUNKS> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char('"')?;
+        for x in (*self).chunks() {
+            for ch in x.chars() {
+                for y in ch.escape_debug() {
+                    f.write_char(y)?;
+                }
+            }
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Yangon;
+use ::serde::{
+    de::{Deserializer, Error as DeError, Unexpected, Visitor},
+    ser::{Serialize, Serializer},
+    Deserialize,
+};
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    str,
+};
+
+
+impl<const N: usize> Serialize for Yangon<N> {
+    fn serialize<S>(self: &Self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str((*self).as_str())
+    }
+}
+
+
+struct YangonVisitor<const N: usize> {
+    truncate: bool,
+}
+
+
+impl<const N: usize> YangonVisitor<N> {
+    fn build<E: DeError>(self: &Self, slice: &str) -> Result<Yangon<N>, E> {
+        if slice.len() <= N {
+            return Ok(Yangon::from(slice));
+        }
+        if !(*self).truncate {
+            return Err(E::invalid_length(slice.len(), self));
+        }
+        let mut end_idx: usize = N;
+        while !slice.is_char_boundary(end_idx) {
+            end_idx -= 1;
+        }
+        Ok(Yangon::from(&slice[..end_idx]))
+    }
+}
+
+
+impl<'de, const N: usize> Visitor<'de> for YangonVisitor<N> {
+    type Value = Yangon<N>;
+
+    fn expecting(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "a string of at most {} bytes", N)
+    }
+
+    fn visit_str<E: DeError>(self, slice: &str) -> Result<Self::Value, E> {
+        self.build(slice)
+    }
+
+    fn visit_borrowed_str<E: DeError>(self, slice: &'de str) -> Result<Self::Value, E> {
+        self.build(slice)
+    }
+
+    fn visit_bytes<E: DeError>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        match str::from_utf8(bytes) {
+            Ok(slice) => self.build(slice),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(bytes), &self)),
+        }
+    }
+}
+
+
+impl<'de, const N: usize> Deserialize<'de> for Yangon<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(YangonVisitor::<N> { truncate: false })
+    }
+}
+
+
+pub mod truncate {
+    use super::YangonVisitor;
+    use crate::Yangon;
+    use ::serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, const N: usize>(value: &Yangon<N>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<Yangon<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(YangonVisitor::<N> { truncate: true })
+    }
+}
@@ -0,0 +1,373 @@
+use crate::{yGeneric, yPattern, Yangon};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+
+#[derive(Clone)]
+enum Repr<const N: usize> {
+    Inline(Yangon<N>),
+    Heap(String),
+}
+
+
+#[derive(Clone)]
+pub struct SmallYangon<const N: usize = 10240> {
+    repr: Repr<N>,
+}
+
+
+impl<const N: usize> SmallYangon<N> {
+    
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            repr: Repr::Inline(Yangon::with_capacity()),
+        }
+    }
+
+    
+    #[inline]
+    pub fn is_inline(self: &Self) -> bool {
+        matches!((*self).repr, Repr::Inline(_))
+    }
+
+    
+    #[inline]
+    pub fn spilled(self: &Self) -> bool {
+        !(*self).is_inline()
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).as_str().len()
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len() == 0
+    }
+
+    
+    pub fn capacity(self: &Self) -> usize {
+        match &(*self).repr {
+            Repr::Inline(inner) => inner.capacity(),
+            Repr::Heap(string) => string.capacity(),
+        }
+    }
+
+    
+    pub fn as_str(self: &Self) -> &str {
+        match &(*self).repr {
+            Repr::Inline(inner) => inner.as_str(),
+            Repr::Heap(string) => string.as_str(),
+        }
+    }
+
+    
+    fn spill(self: &mut Self, extra: usize) -> &mut String {
+        if let Repr::Inline(inner) = &(*self).repr {
+            let mut string: String = String::with_capacity(inner.len() + extra);
+            string.push_str(inner.as_str());
+            (*self).repr = Repr::Heap(string);
+        }
+        match &mut (*self).repr {
+            Repr::Heap(string) => string,
+            Repr::Inline(_) => unreachable!(),
+        }
+    }
+
+    
+    fn room(self: &Self) -> usize {
+        match &(*self).repr {
+            Repr::Inline(inner) => inner.capacity() - inner.len(),
+            Repr::Heap(_) => usize::MAX,
+        }
+    }
+
+    
+    pub fn push(self: &mut Self, ch: char) {
+        if let Repr::Inline(inner) = &mut (*self).repr {
+            if inner.push(ch).is_ok() {
+                return;
+            }
+        }
+        (*self).spill(ch.len_utf8()).push(ch);
+    }
+
+    
+    pub fn push_str(self: &mut Self, slice: &str) {
+        if let Repr::Inline(inner) = &mut (*self).repr {
+            if inner.push_str(slice).is_ok() {
+                return;
+            }
+        }
+        (*self).spill(slice.len()).push_str(slice);
+    }
+
+    
+    pub fn insert(self: &mut Self, idx: usize, ch: char) {
+        if (*self).room() < ch.len_utf8() {
+            (*self).spill(ch.len_utf8());
+        }
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.insert(idx, ch),
+            Repr::Heap(string) => string.insert(idx, ch),
+        }
+    }
+
+    
+    pub fn remove(self: &mut Self, idx: usize) -> char {
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.remove(idx),
+            Repr::Heap(string) => string.remove(idx),
+        }
+    }
+
+    
+    pub fn pop(self: &mut Self) -> Option<char> {
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.pop(),
+            Repr::Heap(string) => string.pop(),
+        }
+    }
+
+    
+    pub fn truncate(self: &mut Self, t_cate: usize) {
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.truncate(t_cate),
+            Repr::Heap(string) => string.truncate(t_cate),
+        }
+    }
+
+    
+    pub fn clear(self: &mut Self) {
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.clear(),
+            Repr::Heap(string) => string.clear(),
+        }
+    }
+
+    
+    pub fn retain<F>(self: &mut Self, closure: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        match &mut (*self).repr {
+            Repr::Inline(inner) => inner.retain(closure),
+            Repr::Heap(string) => string.retain(closure),
+        }
+    }
+
+    
+    pub fn replace<'y, G: yGeneric<'y, C>, const C: usize>(self: &Self, pre: G, upg: &str) -> Self {
+        match pre.iden() {
+            yPattern::Slice(slice) => (*self).replace_it(slice, upg),
+            yPattern::Char(ch) => (*self).replace_it(ch.encode_utf8(&mut [0, 0, 0, 0]), upg),
+            yPattern::CharSlice(ch_slice) => {
+                let mut inst: Self = (*self).clone();
+                for x in ch_slice {
+                    inst = inst.replace_it(x.encode_utf8(&mut [0, 0, 0, 0]), upg);
+                }
+                inst
+            }
+            yPattern::Closure(closure) => {
+                let slice: &str = (*self).as_str();
+                let mut new_len: usize = 0;
+                for ch in slice.chars() {
+                    new_len += if closure(ch) { upg.len() } else { ch.len_utf8() };
+                }
+                let repr: Repr<N> = match &(*self).repr {
+                    Repr::Inline(inner) if new_len <= inner.capacity() => {
+                        Repr::Inline(inner.replace::<fn(char) -> bool, 0>(closure, upg))
+                    }
+                    _ => Repr::Heap(slice.replace(closure, upg)),
+                };
+                Self { repr }
+            }
+        }
+    }
+
+    
+    fn replace_it(self: &Self, pre: &str, upg: &str) -> Self {
+        let slice: &str = (*self).as_str();
+        let count: usize = slice.matches(pre).count();
+        let new_len: usize = slice.len() - count * pre.len() + count * upg.len();
+        let repr: Repr<N> = match &(*self).repr {
+            Repr::Inline(inner) if new_len <= inner.capacity() => {
+                Repr::Inline(inner.replace::<&str, 0>(pre, upg))
+            }
+            _ => Repr::Heap(slice.replace(pre, upg)),
+        };
+        Self { repr }
+    }
+
+    
+    pub fn into_string(self: Self) -> String {
+        match self.repr {
+            Repr::Inline(inner) => inner.to_string(),
+            Repr::Heap(string) => string,
+        }
+    }
+}
+
+
+impl<const N: usize> Default for SmallYangon<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize> From<&str> for SmallYangon<N> {
+    fn from(slice: &str) -> Self {
+        let mut inst: Self = Self::new();
+        inst.push_str(slice);
+        inst
+    }
+}
+
+
+impl<const N: usize> From<String> for SmallYangon<N> {
+    fn from(string: String) -> Self {
+        if string.len() <= N {
+            Self::from(string.as_str())
+        } else {
+            Self {
+                repr: Repr::Heap(string),
+            }
+        }
+    }
+}
+
+
+impl<const N: usize> From<Yangon<N>> for SmallYangon<N> {
+    fn from(yangon: Yangon<N>) -> Self {
+        Self {
+            repr: Repr::Inline(yangon),
+        }
+    }
+}
+
+
+impl<const N: usize> FromStr for SmallYangon<N> {
+    type Err = Infallible;
+    fn from_str(slice: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(slice))
+    }
+}
+
+
+impl<const N: usize> Extend<char> for SmallYangon<N> {
+    fn extend<I: IntoIterator<Item = char>>(self: &mut Self, iter: I) {
+        for x in iter {
+            (*self).push(x);
+        }
+    }
+}
+
+
+impl<'a, const N: usize> Extend<&'a str> for SmallYangon<N> {
+    fn extend<I: IntoIterator<Item = &'a str>>(self: &mut Self, iter: I) {
+        for x in iter {
+            (*self).push_str(x);
+        }
+    }
+}
+
+
+impl<const N: usize> Write for SmallYangon<N> {
+    fn write_str(self: &mut Self, slice: &str) -> FmtResult {
+        (*self).push_str(slice);
+        Ok(())
+    }
+}
+
+
+impl<const N: usize> Deref for SmallYangon<N> {
+    type Target = str;
+    fn deref(self: &Self) -> &Self::Target {
+        (*self).as_str()
+    }
+}
+
+
+impl<const N: usize> AsRef<str> for SmallYangon<N> {
+    fn as_ref(self: &Self) -> &str {
+        (*self).as_str()
+    }
+}
+
+
+impl<const N: usize> Borrow<str> for SmallYangon<N> {
+    fn borrow(self: &Self) -> &str {
+        (*self).as_str()
+    }
+}
+
+
+impl<const N: usize, const M: usize> PartialEq<SmallYangon<M>> for SmallYangon<N> {
+    fn eq(self: &Self, other: &SmallYangon<M>) -> bool {
+        (*self).as_str() == other.as_str()
+    }
+}
+
+
+impl<const N: usize> Eq for SmallYangon<N> {}
+
+
+impl<const N: usize> PartialEq<str> for SmallYangon<N> {
+    fn eq(self: &Self, other: &str) -> bool {
+        (*self).as_str() == other
+    }
+}
+
+
+impl<const N: usize> PartialEq<&str> for SmallYangon<N> {
+    fn eq(self: &Self, other: &&str) -> bool {
+        (*self).as_str() == *other
+    }
+}
+
+
+impl<const N: usize> PartialOrd for SmallYangon<N> {
+    fn partial_cmp(self: &Self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+impl<const N: usize> Ord for SmallYangon<N> {
+    fn cmp(self: &Self, other: &Self) -> Ordering {
+        (*self).as_str().cmp(other.as_str())
+    }
+}
+
+
+impl<const N: usize> Hash for SmallYangon<N> {
+    fn hash<H: Hasher>(self: &Self, state: &mut H) {
+        (*self).as_str().hash(state)
+    }
+}
+
+
+impl<const N: usize> Display for SmallYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt((*self).as_str(), f)
+    }
+}
+
+
+impl<const N: usize> Debug for SmallYangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt((*self).as_str(), f)
+    }
+}
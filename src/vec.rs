@@ -0,0 +1,141 @@
+use crate::{yError, Yangon};
+use std::{
+    array,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    ops::Deref,
+    slice,
+};
+
+
+#[derive(Clone)]
+pub struct YangonVec<const N: usize, const M: usize> {
+    list: [Yangon<N>; M],
+    len: usize,
+}
+
+
+impl<const N: usize, const M: usize> YangonVec<N, M> {
+    
+    pub fn new() -> Self {
+        Self {
+            list: array::from_fn(|_| Yangon::with_capacity()),
+            len: 0,
+        }
+    }
+
+    
+    pub fn push(self: &mut Self, slice: &str) -> Result<(), yError> {
+        let len: usize = (*self).len;
+        if len == M {
+            return Err(yError::TooManyFields);
+        }
+        if slice.len() > N {
+            return Err(yError::FieldTooLong);
+        }
+        let field: &mut Yangon<N> = &mut (*self).list[len];
+        field.clear();
+        field.push_str(slice)?;
+        (*self).len += 1;
+        Ok(())
+    }
+
+    
+    pub(crate) fn extend_from<'a, I>(self: &mut Self, iter: I) -> Result<(), yError>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        for x in iter {
+            (*self).push(x)?;
+        }
+        Ok(())
+    }
+
+    
+    pub fn pop(self: &mut Self) -> Option<Yangon<N>> {
+        if (*self).len == 0 {
+            return None;
+        }
+        (*self).len -= 1;
+        let len: usize = (*self).len;
+        Some(std::mem::take(&mut (*self).list[len]))
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        M
+    }
+
+    
+    #[inline]
+    pub fn as_slice(self: &Self) -> &[Yangon<N>] {
+        &(*self).list[..(*self).len]
+    }
+
+    
+    #[inline]
+    pub fn iter(self: &Self) -> slice::Iter<'_, Yangon<N>> {
+        (*self).as_slice().iter()
+    }
+
+    
+    #[inline]
+    pub fn clear(self: &mut Self) {
+        (*self).len = 0;
+    }
+
+    
+    pub fn join<const K: usize>(self: &Self, sep: &str) -> Result<Yangon<K>, yError> {
+        let mut inst: Yangon<K> = Yangon::with_capacity();
+        for (idx, x) in (*self).iter().enumerate() {
+            if idx > 0 {
+                inst.push_str(sep)?;
+            }
+            inst.push_str(x.as_str())?;
+        }
+        Ok(inst)
+    }
+}
+
+
+impl<const N: usize, const M: usize> Default for YangonVec<N, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl<const N: usize, const M: usize> Deref for YangonVec<N, M> {
+    type Target = [Yangon<N>];
+    fn deref(self: &Self) -> &Self::Target {
+        (*self).as_slice()
+    }
+}
+
+
+impl<'a, const N: usize, const M: usize> IntoIterator for &'a YangonVec<N, M> {
+    type Item = &'a Yangon<N>;
+    type IntoIter = slice::Iter<'a, Yangon<N>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+impl<const N: usize, const M: usize> Debug for YangonVec<N, M> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list().entries((*self).iter()).finish()
+    }
+}
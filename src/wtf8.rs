@@ -0,0 +1,268 @@
+use crate::{yError, Yangon};
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    mem::MaybeUninit,
+    slice::from_raw_parts,
+    str,
+};
+
+
+#[derive(Clone)]
+pub struct Wtf8Yangon<const N: usize = 10240> {
+    list: [MaybeUninit<u8>; N],
+    len: usize,
+}
+
+
+impl<const N: usize> Wtf8Yangon<N> {
+    
+    #[inline]
+    pub fn with_capacity() -> Self {
+        Self {
+            list: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity()
+    }
+
+    
+    #[inline]
+    pub fn len(self: &Self) -> usize {
+        (*self).len
+    }
+
+    
+    #[inline]
+    pub fn is_empty(self: &Self) -> bool {
+        (*self).len == 0
+    }
+
+    
+    #[inline]
+    pub fn capacity(self: &Self) -> usize {
+        N
+    }
+
+    
+    #[inline]
+    pub fn as_bytes(self: &Self) -> &[u8] {
+        unsafe { from_raw_parts((*self).list.as_ptr() as *const u8, (*self).len) }
+    }
+
+    
+    fn push_code_point(self: &mut Self, code: u32) -> Result<(), yError> {
+        let mut bind: [u8; 4] = [0, 0, 0, 0];
+        let byt_len: usize = if code < 0x80 {
+            bind[0] = code as u8;
+            1
+        } else if code < 0x800 {
+            bind[0] = 0xC0 | (code >> 6) as u8;
+            bind[1] = 0x80 | (code & 0x3F) as u8;
+            2
+        } else if code < 0x10000 {
+            bind[0] = 0xE0 | (code >> 12) as u8;
+            bind[1] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            bind[2] = 0x80 | (code & 0x3F) as u8;
+            3
+        } else {
+            bind[0] = 0xF0 | (code >> 18) as u8;
+            bind[1] = 0x80 | ((code >> 12) & 0x3F) as u8;
+            bind[2] = 0x80 | ((code >> 6) & 0x3F) as u8;
+            bind[3] = 0x80 | (code & 0x3F) as u8;
+            4
+        };
+        let len: usize = (*self).len;
+        if len + byt_len > N {
+            return Err(yError::CapacityOverflow);
+        }
+        let ptr: *mut u8 = (*self).list.as_mut_ptr() as *mut u8;
+        for (idx, &x) in bind[..byt_len].iter().enumerate() {
+            unsafe {
+                *ptr.add(len + idx) = x;
+            }
+        }
+        (*self).len += byt_len;
+        Ok(())
+    }
+
+    
+    pub fn from_utf16(list: &[u16]) -> Result<Self, yError> {
+        let mut inst: Self = Self::with_capacity();
+        let len: usize = list.len();
+        let mut idx: usize = 0;
+        while idx < len {
+            let unit: u32 = list[idx] as u32;
+            if (0xD800..0xDC00).contains(&unit) && idx + 1 < len {
+                let next: u32 = list[idx + 1] as u32;
+                if (0xDC00..0xE000).contains(&next) {
+                    inst.push_code_point(0x10000 + ((unit - 0xD800) << 10) + (next - 0xDC00))?;
+                    idx += 2;
+                    continue;
+                }
+            }
+            inst.push_code_point(unit)?;
+            idx += 1;
+        }
+        Ok(inst)
+    }
+
+    
+    pub fn from_yangon<const M: usize>(yangon: &Yangon<M>) -> Result<Self, yError> {
+        let bytes: &[u8] = yangon.as_bytes();
+        if bytes.len() > N {
+            return Err(yError::CapacityOverflow);
+        }
+        let mut inst: Self = Self::with_capacity();
+        let ptr: *mut u8 = inst.list.as_mut_ptr() as *mut u8;
+        for (idx, &x) in bytes.iter().enumerate() {
+            unsafe {
+                *ptr.add(idx) = x;
+            }
+        }
+        inst.len = bytes.len();
+        Ok(inst)
+    }
+
+    
+    pub fn code_points(self: &Self) -> CodePoints<'_> {
+        CodePoints {
+            bytes: (*self).as_bytes(),
+            idx: 0,
+        }
+    }
+
+    
+    pub fn is_well_formed(self: &Self) -> bool {
+        str::from_utf8((*self).as_bytes()).is_ok()
+    }
+
+    
+    pub fn as_str(self: &Self) -> Option<&str> {
+        str::from_utf8((*self).as_bytes()).ok()
+    }
+
+    
+    pub fn encode_utf16<const M: usize>(self: &Self) -> Result<([u16; M], usize), yError> {
+        let mut list: [u16; M] = [0; M];
+        let mut idx: usize = 0;
+        for code in (*self).code_points() {
+            if code >= 0x10000 {
+                if idx + 2 > M {
+                    return Err(yError::CapacityOverflow);
+                }
+                let code: u32 = code - 0x10000;
+                list[idx] = 0xD800 | (code >> 10) as u16;
+                list[idx + 1] = 0xDC00 | (code & 0x3FF) as u16;
+                idx += 2;
+            } else {
+                if idx >= M {
+                    return Err(yError::CapacityOverflow);
+                }
+                list[idx] = code as u16;
+                idx += 1;
+            }
+        }
+        Ok((list, idx))
+    }
+
+    
+    pub fn to_yangon(self: &Self) -> Result<Yangon<N>, yError> {
+        match (*self).as_str() {
+            Some(slice) => Ok(Yangon::from(slice)),
+            None => Err(yError::FromUtf16Error),
+        }
+    }
+
+    
+    pub fn to_yangon_lossy(self: &Self) -> Yangon<N> {
+        let mut inst: Yangon<N> = Yangon::with_capacity();
+        for code in (*self).code_points() {
+            let ch: char = char::from_u32(code).unwrap_or('\u{FFFD}');
+            if inst.push(ch).is_err() {
+                break;
+            }
+        }
+        inst
+    }
+}
+
+
+pub struct CodePoints<'w> {
+    bytes: &'w [u8],
+    idx: usize,
+}
+
+
+impl Iterator for CodePoints<'_> {
+    type Item = u32;
+    fn next(self: &mut Self) -> Option<u32> {
+        let bytes: &[u8] = (*self).bytes;
+        let idx: usize = (*self).idx;
+        if idx >= bytes.len() {
+            return None;
+        }
+        let frt_byt: u32 = bytes[idx] as u32;
+        let (code, byt_len): (u32, usize) = if frt_byt < 0x80 {
+            (frt_byt, 1)
+        } else if frt_byt < 0xE0 {
+            (((frt_byt & 0x1F) << 6) | (bytes[idx + 1] as u32 & 0x3F), 2)
+        } else if frt_byt < 0xF0 {
+            (
+                ((frt_byt & 0x0F) << 12)
+                    | ((bytes[idx + 1] as u32 & 0x3F) << 6)
+                    | (bytes[idx + 2] as u32 & 0x3F),
+                3,
+            )
+        } else {
+            (
+                ((frt_byt & 0x07) << 18)
+                    | ((bytes[idx + 1] as u32 & 0x3F) << 12)
+                    | ((bytes[idx + 2] as u32 & 0x3F) << 6)
+                    | (bytes[idx + 3] as u32 & 0x3F),
+                4,
+            )
+        };
+        (*self).idx += byt_len;
+        Some(code)
+    }
+}
+
+
+impl<const N: usize> Default for Wtf8Yangon<N> {
+    fn default() -> Self {
+        Self::with_capacity()
+    }
+}
+
+
+impl<const N: usize> Display for Wtf8Yangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        for code in (*self).code_points() {
+            f.write_char(char::from_u32(code).unwrap_or('\u{FFFD}'))?;
+        }
+        Ok(())
+    }
+}
+
+
+impl<const N: usize> Debug for Wtf8Yangon<N> {
+    fn fmt(self: &Self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char('"')?;
+        for code in (*self).code_points() {
+            match char::from_u32(code) {
+                Some(ch) => {
+                    for x in ch.escape_debug() {
+                        f.write_char(x)?;
+                    }
+                }
+                None => write!(f, "\\u{{{:X}}}", code)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use yangon::*;
+#[test]
+fn test_arbitrary_respects_capacity() {
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&data);
+    for _ in 0..64 {
+        let y: Yangon<5> = Yangon::arbitrary(&mut u).unwrap();
+        assert!(y.len() <= 5);
+        assert!(std::str::from_utf8(y.as_bytes()).is_ok());
+    }
+}
+#[test]
+fn test_arbitrary_truncates_on_char_boundary() {
+    let u = Unstructured::new("a世界".as_bytes());
+    let y: Yangon<5> = Yangon::arbitrary_take_rest(u).unwrap();
+    assert_eq!(y.as_str(), "a世");
+}
+#[derive(Arbitrary, Debug)]
+struct Message {
+    topic: Yangon<8>,
+    body: Yangon<32>,
+}
+#[test]
+fn test_arbitrary_in_derived_struct() {
+    let mut u = Unstructured::new(b"\x03abc\x05hello world");
+    let msg = Message::arbitrary(&mut u).unwrap();
+    assert!(msg.topic.len() <= 8 && msg.body.len() <= 32);
+}
@@ -0,0 +1,49 @@
+#include <stdio.h>
+#include <string.h>
+#include "yangon.h"
+
+#define CHECK(cond)                                          \
+    do {                                                     \
+        if (!(cond)) {                                       \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #cond); \
+            return 1;                                        \
+        }                                                    \
+    } while (0)
+
+static int equals(const yangon_t *y, const char *expected) {
+    size_t len = strlen(expected);
+    return yangon_len(y) == len && memcmp(yangon_as_ptr(y), expected, len) == 0;
+}
+
+int main(void) {
+    yangon_t y;
+    char big[YANGON_CAPACITY + 1];
+
+    CHECK(yangon_storage_size() == sizeof(yangon_t));
+    CHECK(yangon_init(&y) == YANGON_OK);
+    CHECK(yangon_capacity(&y) == YANGON_CAPACITY);
+    CHECK(yangon_push_str(&y, "hello ", 6) == YANGON_OK);
+    CHECK(yangon_push_str(&y, "world", 5) == YANGON_OK);
+    CHECK(equals(&y, "hello world"));
+
+    CHECK(yangon_replace(&y, "o", 1, "0", 1) == YANGON_OK);
+    CHECK(equals(&y, "hell0 w0rld"));
+    CHECK(yangon_replace(&y, "l", 1, "LL", 2) == YANGON_OK);
+    CHECK(equals(&y, "heLLLL0 w0rLLd"));
+
+    CHECK(yangon_push_str(&y, "\xff", 1) == YANGON_FROM_UTF8_ERROR);
+    CHECK(yangon_push_str(NULL, "x", 1) == YANGON_NULL_POINTER);
+
+    memset(big, 'a', sizeof(big));
+    CHECK(yangon_push_str(&y, big, sizeof(big)) == YANGON_CAPACITY_OVERFLOW);
+    CHECK(equals(&y, "heLLLL0 w0rLLd"));
+
+    yangon_clear(&y);
+    CHECK(yangon_len(&y) == 0);
+    CHECK(yangon_push_str(&y, big, YANGON_CAPACITY) == YANGON_OK);
+    CHECK(yangon_replace(&y, "a", 1, "bb", 2) == YANGON_CAPACITY_OVERFLOW);
+    CHECK(yangon_len(&y) == YANGON_CAPACITY);
+
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,27 @@
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck_pod_cast() {
+    use yangon::FixedStr;
+    let records: [FixedStr<8>; 2] = ["first".parse().unwrap(), "二".parse().unwrap()];
+    let bytes: &[u8] = bytemuck::cast_slice(&records);
+    assert_eq!(bytes.len(), 24);
+    let back: &[FixedStr<8>] = bytemuck::cast_slice(bytes);
+    assert_eq!(back[1].as_str().unwrap(), "二");
+    let zeroed: FixedStr<8> = bytemuck::Zeroable::zeroed();
+    assert_eq!(zeroed.as_str().unwrap(), "");
+}
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy_from_and_into_bytes() {
+    use yangon::{yError, FixedStr};
+    use zerocopy::{FromBytes, IntoBytes};
+    let f: FixedStr<8> = "mmap".parse().unwrap();
+    let bytes = f.as_bytes().to_vec();
+    assert_eq!(IntoBytes::as_bytes(&f), &bytes[..]);
+    let back = FixedStr::<8>::read_from_bytes(&bytes).unwrap();
+    assert_eq!(back.as_str().unwrap(), "mmap");
+    let mut corrupt = bytes.clone();
+    corrupt[0] = 0xFF;
+    let back = FixedStr::<8>::read_from_bytes(&corrupt).unwrap();
+    assert!(matches!(back.as_str(), Err(yError::CapacityOverflow)));
+}
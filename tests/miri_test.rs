@@ -1,3 +1,4 @@
+#![allow(unused, clippy::approx_constant, clippy::len_zero, clippy::print_literal, clippy::write_literal, clippy::redundant_closure, clippy::collapsible_match, clippy::bool_assert_comparison)]
 use yangon::*;
 #[test]
 fn test_all_valid_unicode_planes() {
@@ -1716,3 +1717,1087 @@ fn test_replace_range_same_size() {
     y.replace_range(0..5, "World");
     assert_eq!(y.as_str(), "World");
 }
+#[test]
+fn test_display_width_ascii_cjk_and_combining() {
+    assert_eq!(Yangon::<64>::from("hello").display_width(), 5);
+    assert_eq!(Yangon::<64>::from("世界").display_width(), 4);
+    assert_eq!(Yangon::<64>::from("e\u{0301}").display_width(), 1);
+    assert_eq!(Yangon::<64>::from("🦀x").display_width(), 3);
+    assert_eq!(Yangon::<64>::from("a\u{200B}b").display_width(), 2);
+}
+#[test]
+fn test_truncate_to_width_with_ellipsis() {
+    let mut y = Yangon::<64>::from("Hello World");
+    y.truncate_to_width(8, "…").unwrap();
+    assert_eq!(y.as_str(), "Hello W…");
+    assert_eq!(y.display_width(), 8);
+    let mut y = Yangon::<64>::from("世界こんにちは");
+    y.truncate_to_width(7, "..").unwrap();
+    assert_eq!(y.as_str(), "世界..");
+    let mut y = Yangon::<64>::from("short");
+    y.truncate_to_width(10, "…").unwrap();
+    assert_eq!(y.as_str(), "short");
+}
+#[test]
+fn test_truncate_to_width_keeps_combining_marks() {
+    let mut y = Yangon::<64>::from("ae\u{0301}bc");
+    y.truncate_to_width(2, "").unwrap();
+    assert_eq!(y.as_str(), "ae\u{0301}");
+}
+#[test]
+fn test_truncate_to_width_overflow_leaves_unchanged() {
+    let mut y = Yangon::<4>::from("abcd");
+    assert!(y.truncate_to_width(3, "…").is_err());
+    assert_eq!(y.as_str(), "abcd");
+}
+#[test]
+fn test_pad_to_width_alignments() {
+    let mut y = Yangon::<64>::from("世");
+    y.pad_to_width(5, yAlign::Left).unwrap();
+    assert_eq!(y.as_str(), "世   ");
+    let mut y = Yangon::<64>::from("ab");
+    y.pad_to_width(5, yAlign::Right).unwrap();
+    assert_eq!(y.as_str(), "   ab");
+    let mut y = Yangon::<64>::from("ab");
+    y.pad_to_width(5, yAlign::Center).unwrap();
+    assert_eq!(y.as_str(), " ab  ");
+    let mut y = Yangon::<4>::from("ab");
+    assert!(y.pad_to_width(5, yAlign::Left).is_err());
+    assert_eq!(y.as_str(), "ab");
+}
+#[test]
+fn test_center_with_fill() {
+    let mut y = Yangon::<64>::from("ab");
+    y.center(6, '*').unwrap();
+    assert_eq!(y.as_str(), "**ab**");
+    let mut y = Yangon::<64>::from("ab");
+    y.center(7, '世').unwrap();
+    assert_eq!(y.as_str(), "世ab世 ");
+    assert_eq!(y.display_width(), 7);
+}
+#[test]
+fn test_from_utf16_valid_and_invalid() {
+    let y = Yangon::<64>::from_utf16(&[0x48, 0x69, 0xD83E, 0xDD80]).unwrap();
+    assert_eq!(y.as_str(), "Hi🦀");
+    assert!(matches!(Yangon::<64>::from_utf16(&[0x48, 0xD800]), Err(yError::FromUtf16Error)));
+    assert!(matches!(Yangon::<2>::from_utf16(&[0x48, 0x49, 0x4A]), Err(yError::CapacityOverflow)));
+    let y = unsafe { Yangon::<64>::from_utf16_unchecked(&[0x4E16, 0x754C]) };
+    assert_eq!(y.as_str(), "世界");
+}
+#[test]
+fn test_from_utf16_lossy_replaces_and_stops_at_capacity() {
+    let y = Yangon::<64>::from_utf16_lossy(&[0x61, 0xDC00, 0x62, 0xD800]);
+    assert_eq!(y.as_str(), "a\u{FFFD}b\u{FFFD}");
+    let y = Yangon::<4>::from_utf16_lossy(&[0x61, 0xD800, 0x62]);
+    assert_eq!(y.as_str(), "a\u{FFFD}");
+}
+#[test]
+fn test_from_utf16le_be() {
+    let y = Yangon::<64>::from_utf16le(&[0x48, 0x00, 0x3D, 0xD8, 0x00, 0xDE]).unwrap();
+    assert_eq!(y.as_str(), "H😀");
+    let y = Yangon::<64>::from_utf16be(&[0x00, 0x48, 0xD8, 0x3D, 0xDE, 0x00]).unwrap();
+    assert_eq!(y.as_str(), "H😀");
+    assert!(matches!(Yangon::<64>::from_utf16le(&[0x48]), Err(yError::FromUtf16Error)));
+}
+#[test]
+fn test_encode_utf16_round_trip() {
+    let y = Yangon::<64>::from("a世🦀");
+    let (units, len) = y.encode_utf16::<8>().unwrap();
+    assert_eq!(&units[..len], &[0x61, 0x4E16, 0xD83E, 0xDD80]);
+    assert_eq!(Yangon::<64>::from_utf16(&units[..len]).unwrap().as_str(), "a世🦀");
+    assert!(matches!(y.encode_utf16::<3>(), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_wtf8_unpaired_surrogates_round_trip() {
+    let input: [u16; 5] = [0x61, 0xD800, 0xD83E, 0xDD80, 0xDFFF];
+    let w = Wtf8Yangon::<64>::from_utf16(&input).unwrap();
+    assert!(!w.is_well_formed());
+    assert!(w.as_str().is_none());
+    assert_eq!(w.as_bytes(), &[0x61, 0xED, 0xA0, 0x80, 0xF0, 0x9F, 0xA6, 0x80, 0xED, 0xBF, 0xBF]);
+    let (units, len) = w.encode_utf16::<8>().unwrap();
+    assert_eq!(&units[..len], &input);
+    assert!(matches!(w.to_yangon(), Err(yError::FromUtf16Error)));
+    assert_eq!(w.to_yangon_lossy().as_str(), "a\u{FFFD}🦀\u{FFFD}");
+    assert_eq!(format!("{:?}", w), "\"a\\u{D800}🦀\\u{DFFF}\"");
+}
+#[test]
+fn test_wtf8_well_formed_converts_back() {
+    let y = Yangon::<64>::from("héllo");
+    let w = Wtf8Yangon::<64>::from_yangon(&y).unwrap();
+    assert!(w.is_well_formed());
+    assert_eq!(w.to_yangon().unwrap().as_str(), "héllo");
+    assert!(matches!(Wtf8Yangon::<2>::from_utf16(&[0xD800]), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_from_latin1_expands_to_utf8() {
+    let y = Yangon::<64>::from_latin1(&[0x63, 0x61, 0x66, 0xE9, 0xFF]).unwrap();
+    assert_eq!(y.as_str(), "caféÿ");
+    assert_eq!(y.len(), 7);
+    assert!(matches!(Yangon::<4>::from_latin1(&[0xE9, 0xE9, 0xE9]), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_from_windows_1252_table() {
+    let y = Yangon::<64>::from_windows_1252(&[0x80, 0x93, 0x41, 0x94, 0x99, 0x81, 0xA3]).unwrap();
+    assert_eq!(y.as_str(), "€\u{201C}A\u{201D}™\u{81}£");
+}
+#[test]
+fn test_from_ascii_rejects_high_bytes() {
+    assert_eq!(Yangon::<64>::from_ascii(b"plain").unwrap().as_str(), "plain");
+    assert!(matches!(Yangon::<64>::from_ascii(&[0x41, 0x80]), Err(yError::EncodingError)));
+}
+#[test]
+fn test_encode_latin1_policies() {
+    let y = Yangon::<64>::from("é€x");
+    let (bytes, len) = y.encode_latin1::<8>(yUnmappable::Replace(b'?')).unwrap();
+    assert_eq!(&bytes[..len], &[0xE9, b'?', b'x']);
+    let (bytes, len) = y.encode_latin1::<8>(yUnmappable::Skip).unwrap();
+    assert_eq!(&bytes[..len], &[0xE9, b'x']);
+    assert!(matches!(y.encode_latin1::<8>(yUnmappable::Error), Err(yError::EncodingError)));
+    assert!(matches!(y.encode_latin1::<1>(yUnmappable::Skip), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_encode_windows_1252_round_trip() {
+    let input: [u8; 6] = [0x80, 0x8A, 0x9F, 0x20, 0xE9, 0x7E];
+    let y = Yangon::<64>::from_windows_1252(&input).unwrap();
+    let (bytes, len) = y.encode_windows_1252::<8>(yUnmappable::Error).unwrap();
+    assert_eq!(&bytes[..len], &input);
+    let y = Yangon::<64>::from("世");
+    assert!(matches!(y.encode_windows_1252::<8>(yUnmappable::Error), Err(yError::EncodingError)));
+}
+#[test]
+fn test_from_utf8_with_replace_char_and_str() {
+    let bytes: [u8; 6] = [b'a', 0xFF, b'b', 0xE4, 0xB8, b'c'];
+    let (y, report) = Yangon::<64>::from_utf8_with(&bytes, yPolicy::ReplaceChar('?'));
+    assert_eq!(y.as_str(), "a?b?c");
+    assert_eq!(report, yReport { invalid_sequences: 2, truncated_bytes: 0 });
+    let (y, _) = Yangon::<64>::from_utf8_with(&bytes, yPolicy::ReplaceStr("<bad>"));
+    assert_eq!(y.as_str(), "a<bad>b<bad>c");
+}
+#[test]
+fn test_from_utf8_with_skip_escape_stop() {
+    let bytes: [u8; 5] = [b'x', 0xC0, 0x80, b'y', 0xF0];
+    let (y, report) = Yangon::<64>::from_utf8_with(&bytes, yPolicy::Skip);
+    assert_eq!(y.as_str(), "xy");
+    assert_eq!(report.invalid_sequences, 3);
+    let (y, _) = Yangon::<64>::from_utf8_with(&bytes, yPolicy::Escape);
+    assert_eq!(y.as_str(), "x\\xC0\\x80y\\xF0");
+    let (y, report) = Yangon::<64>::from_utf8_with(&bytes, yPolicy::Stop);
+    assert_eq!(y.as_str(), "x");
+    assert_eq!(report, yReport { invalid_sequences: 1, truncated_bytes: 4 });
+}
+#[test]
+fn test_from_utf8_with_respects_capacity() {
+    let (y, report) = Yangon::<4>::from_utf8_with("ab世界".as_bytes(), yPolicy::Skip);
+    assert_eq!(y.as_str(), "ab");
+    assert_eq!(report.truncated_bytes, 6);
+    let bytes: [u8; 4] = [b'a', 0xFF, 0xFF, b'b'];
+    let (y, report) = Yangon::<4>::from_utf8_with(&bytes, yPolicy::ReplaceChar('\u{FFFD}'));
+    assert_eq!(y.as_str(), "a\u{FFFD}");
+    assert_eq!(report, yReport { invalid_sequences: 2, truncated_bytes: 2 });
+}
+#[test]
+fn test_from_utf8_lossy_does_not_overflow_capacity() {
+    let bytes: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+    let y = Yangon::<4>::from_utf8_lossy(&bytes);
+    assert_eq!(&*y, "\u{FFFD}");
+}
+#[test]
+fn test_utf8_appender_char_straddles_chunks() {
+    let mut y = Yangon::<64>::with_capacity();
+    let bytes = "a世🦀b".as_bytes();
+    let mut app = Utf8Appender::new(&mut y);
+    app.push_bytes(&bytes[..2]).unwrap();
+    assert_eq!(app.pending(), &bytes[1..2]);
+    app.push_bytes(&bytes[2..3]).unwrap();
+    app.push_bytes(&bytes[3..6]).unwrap();
+    app.push_bytes(&bytes[6..]).unwrap();
+    app.finish().unwrap();
+    assert_eq!(y.as_str(), "a世🦀b");
+}
+#[test]
+fn test_utf8_appender_every_split_point() {
+    let text = "héllo, 世界! 🦀🦀";
+    let bytes = text.as_bytes();
+    for split in 0..=bytes.len() {
+        let mut y = Yangon::<64>::with_capacity();
+        let mut app = Utf8Appender::new(&mut y);
+        app.push_bytes(&bytes[..split]).unwrap();
+        app.push_bytes(&bytes[split..]).unwrap();
+        app.finish().unwrap();
+        assert_eq!(y.as_str(), text);
+    }
+}
+#[test]
+fn test_utf8_appender_strict_rejects_invalid_atomically() {
+    let mut y = Yangon::<64>::from("ok");
+    let mut app = Utf8Appender::new(&mut y);
+    assert!(matches!(app.push_bytes(&[b'x', 0xFF]), Err(yError::FromUtf8Error)));
+    app.push_bytes(&[0xE4, 0xB8]).unwrap();
+    assert!(matches!(app.push_bytes(b"z"), Err(yError::FromUtf8Error)));
+    assert!(matches!(app.finish(), Err(yError::FromUtf8Error)));
+    assert_eq!(y.as_str(), "ok");
+}
+#[test]
+fn test_utf8_appender_lossy_replaces() {
+    let mut y = Yangon::<64>::with_capacity();
+    let mut app = Utf8Appender::lossy(&mut y);
+    app.push_bytes(&[b'a', 0xFF, 0xF0, 0x9F]).unwrap();
+    app.push_bytes(&[b'b', 0xE4]).unwrap();
+    app.finish().unwrap();
+    assert_eq!(y.as_str(), "a\u{FFFD}\u{FFFD}b\u{FFFD}");
+}
+#[test]
+fn test_utf8_appender_capacity_overflow() {
+    let mut y = Yangon::<4>::from("ab");
+    let mut app = Utf8Appender::new(&mut y);
+    assert!(matches!(app.push_bytes("世".as_bytes()), Err(yError::CapacityOverflow)));
+    app.push_bytes(b"cd").unwrap();
+    assert_eq!(y.as_str(), "abcd");
+}
+#[test]
+fn test_eq_and_ord_across_capacities_and_str_types() {
+    use std::borrow::Cow;
+    let a = Yangon::<8>::from("apple");
+    let b = Yangon::<64>::from("apple");
+    let c = Yangon::<16>::from("banana");
+    assert!(a == b);
+    assert!(a < c);
+    assert!(a == *"apple");
+    let owned = String::from("apple");
+    assert!(a == owned);
+    assert!(a == Cow::Borrowed("apple"));
+    assert!("apple" == a);
+    assert!(*"apple" == a);
+    assert!(owned == a);
+    assert!(Cow::<str>::Owned(String::from("apple")) == a);
+    assert!(a < "b");
+    assert!("b" > a);
+    assert!(owned >= a);
+    let mut list = [Yangon::<8>::from("b"), Yangon::<8>::from("c"), Yangon::<8>::from("a")];
+    list.sort();
+    assert_eq!(list[0], "a");
+    assert_eq!(list[2], "c");
+}
+#[test]
+fn test_hash_and_borrow_for_map_lookups() {
+    use std::collections::{BTreeMap, HashMap};
+    use std::hash::{BuildHasher, RandomState};
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(Yangon::<32>::from("key")), state.hash_one("key"));
+    let mut map: HashMap<Yangon<32>, u32> = HashMap::new();
+    map.insert(Yangon::from("alpha"), 1);
+    assert_eq!(map.get("alpha"), Some(&1));
+    let mut tree: BTreeMap<Yangon<32>, u32> = BTreeMap::new();
+    tree.insert(Yangon::from("beta"), 2);
+    tree.insert(Yangon::from("alpha"), 1);
+    assert_eq!(tree.get("beta"), Some(&2));
+    assert_eq!(tree.keys().next().unwrap(), "alpha");
+}
+#[test]
+fn test_default_and_from_str() {
+    let y: Yangon<8> = Yangon::default();
+    assert!(y.is_empty());
+    assert_eq!(y.capacity(), 8);
+    let y: Yangon<8> = "hello".parse().unwrap();
+    assert_eq!(y, "hello");
+    assert!(matches!("too long for it".parse::<Yangon<8>>(), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_extend_and_add() {
+    let mut y = Yangon::<32>::from("ab");
+    y.extend(['c', '世']);
+    y.extend(["de", "f"]);
+    assert_eq!(y, "abc世def");
+    let y = y + "!";
+    assert_eq!(y, "abc世def!");
+    let mut y = Yangon::<32>::new();
+    y += "x";
+    y += "y";
+    assert_eq!(y, "xy");
+}
+#[test]
+#[should_panic(expected = "Capacity Overflow.")]
+fn test_add_assign_panics_on_overflow() {
+    let mut y = Yangon::<2>::from("ab");
+    y += "c";
+}
+#[test]
+fn test_io_write_through_writer() {
+    use std::io::Write;
+    let mut y = Yangon::<64>::with_capacity();
+    let mut w = y.writer();
+    write!(w, "{}-{}", 12, "世界").unwrap();
+    let bytes = "🦀".as_bytes();
+    w.write_all(&bytes[..1]).unwrap();
+    w.write_all(&bytes[1..]).unwrap();
+    w.flush().unwrap();
+    w.finish().unwrap();
+    assert_eq!(y, "12-世界🦀");
+}
+#[test]
+fn test_io_write_errors() {
+    use std::io::{ErrorKind, Write};
+    let mut y = Yangon::<4>::with_capacity();
+    let mut w = y.writer();
+    assert_eq!(w.write(b"abcdef").unwrap(), 4);
+    assert_eq!(w.write_all(b"x").unwrap_err().kind(), ErrorKind::WriteZero);
+    let mut y = Yangon::<16>::with_capacity();
+    let mut w = y.writer();
+    assert_eq!(w.write_all(&[0xFF]).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+#[test]
+fn test_io_reader_read_and_buf_read() {
+    use std::io::{BufRead, Read};
+    let y = Yangon::<64>::from("line one\nline two\n");
+    let mut r = y.reader();
+    let mut buf = [0u8; 4];
+    assert_eq!(r.read(&mut buf).unwrap(), 4);
+    assert_eq!(&buf, b"line");
+    let mut rest = String::new();
+    r.read_line(&mut rest).unwrap();
+    assert_eq!(rest, " one\n");
+    let lines: Vec<String> = r.lines().map(|l| l.unwrap()).collect();
+    assert_eq!(lines, vec!["line two".to_string()]);
+}
+#[test]
+fn test_read_line_from_buf_reader() {
+    use std::io::BufReader;
+    let data = "héllo\n世界🦀\nlast";
+    let mut reader = BufReader::with_capacity(3, data.as_bytes());
+    let mut y = Yangon::<64>::with_capacity();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 7);
+    assert_eq!(y, "héllo\n");
+    y.clear();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 11);
+    assert_eq!(y, "世界🦀\n");
+    y.clear();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 4);
+    assert_eq!(y, "last");
+    y.clear();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 0);
+}
+#[test]
+fn test_read_line_from_stops_at_capacity_on_char_boundary() {
+    use std::io::BufReader;
+    let data = "ab世界\nnext\n";
+    let mut reader = BufReader::with_capacity(2, data.as_bytes());
+    let mut y = Yangon::<7>::with_capacity();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 5);
+    assert_eq!(y, "ab世");
+    y.clear();
+    assert_eq!(y.read_line_from(&mut reader).unwrap(), 4);
+    assert_eq!(y, "界\n");
+}
+#[test]
+fn test_read_line_from_invalid_utf8_restores() {
+    let data: [u8; 4] = [b'a', 0xFF, b'b', b'\n'];
+    let mut reader: &[u8] = &data;
+    let mut y = Yangon::<16>::from("keep");
+    assert!(y.read_line_from(&mut reader).is_err());
+    assert_eq!(y, "keep");
+}
+#[test]
+fn test_fixed_str_layout_and_round_trip() {
+    assert_eq!(std::mem::size_of::<FixedStr<12>>(), 16);
+    assert_eq!(std::mem::align_of::<FixedStr<12>>(), 1);
+    let f: FixedStr<12> = "héllo".parse().unwrap();
+    assert_eq!(f.as_str().unwrap(), "héllo");
+    assert_eq!(f.stored_len(), 6);
+    assert!(f.is_zero_filled());
+    assert_eq!(&f.as_bytes()[..4], &[6, 0, 0, 0]);
+    assert_eq!(&f.as_bytes()[10..], &[0, 0, 0, 0, 0, 0]);
+    let y: Yangon<32> = f.to_yangon().unwrap();
+    assert_eq!(y, "héllo");
+    assert_eq!(FixedStr::<12>::from_yangon(&y).unwrap(), f);
+    assert!(matches!("thirteen byte".parse::<FixedStr<12>>(), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_fixed_str_rejects_corrupted_records() {
+    let mut raw: [u8; 8] = [2, 0, 0, 0, b'o', b'k', 0, 0];
+    assert_eq!(FixedStr::<4>::ref_from_bytes(&raw).unwrap().as_str().unwrap(), "ok");
+    raw[0] = 9;
+    assert!(matches!(FixedStr::<4>::ref_from_bytes(&raw).unwrap().as_str(), Err(yError::CapacityOverflow)));
+    let raw: [u8; 8] = [2, 0, 0, 0, 0xC3, 0x28, 0, 0];
+    let f = FixedStr::<4>::ref_from_bytes(&raw).unwrap();
+    assert!(matches!(f.as_str(), Err(yError::FromUtf8Error)));
+    assert!(matches!(f.to_yangon::<8>(), Err(yError::FromUtf8Error)));
+    assert!(FixedStr::<4>::ref_from_bytes(&raw[..7]).is_err());
+}
+#[test]
+fn test_from_padded_nul_and_space() {
+    let field: [u8; 8] = [b'u', b's', b't', b'a', b'r', 0, 0, 0];
+    assert_eq!(Yangon::<16>::from_padded(&field, yPadding::Nul).unwrap(), "ustar");
+    let field: [u8; 8] = *b"HDR  \0  ";
+    assert_eq!(Yangon::<16>::from_padded(&field, yPadding::Nul).unwrap(), "HDR  ");
+    assert_eq!(Yangon::<16>::from_padded(&field, yPadding::Space).unwrap(), "HDR  \0");
+    let field: [u8; 4] = *b"full";
+    assert_eq!(Yangon::<16>::from_padded(&field, yPadding::Nul).unwrap(), "full");
+    assert!(matches!(Yangon::<2>::from_padded(&field, yPadding::Space), Err(yError::CapacityOverflow)));
+    let field: [u8; 4] = [0xFF, 0, 0, 0];
+    assert!(matches!(Yangon::<16>::from_padded(&field, yPadding::Nul), Err(yError::FromUtf8Error)));
+}
+#[test]
+fn test_to_padded() {
+    let y = Yangon::<8>::from("abc");
+    assert_eq!(y.to_padded(yPadding::Nul), [b'a', b'b', b'c', 0, 0, 0, 0, 0]);
+    assert_eq!(&y.to_padded(yPadding::Space), b"abc     ");
+    let back = Yangon::<8>::from_padded(&y.to_padded(yPadding::Space), yPadding::Space).unwrap();
+    assert_eq!(back, "abc");
+}
+#[test]
+fn test_from_c_buf_and_as_c_str() {
+    let y = Yangon::<16>::from_c_buf(b"name\0garbage").unwrap();
+    assert_eq!(y, "name");
+    assert!(matches!(Yangon::<16>::from_c_buf(b"no terminator"), Err(yError::NulError)));
+    let mut y = Yangon::<8>::from("hi");
+    assert_eq!(y.as_c_str().unwrap().to_bytes_with_nul(), b"hi\0");
+    let mut y = Yangon::<8>::from("a\0b");
+    assert!(matches!(y.as_c_str(), Err(yError::NulError)));
+    let mut y = Yangon::<2>::from("ab");
+    assert!(matches!(y.as_c_str(), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_nul_yangon_keeps_terminator() {
+    let mut y = NulYangon::<8>::new();
+    assert_eq!(y.capacity(), 7);
+    assert_eq!(y.as_c_str().to_bytes_with_nul(), b"\0");
+    y.push_str("abcdef").unwrap();
+    y.push('g').unwrap();
+    assert!(matches!(y.push('h'), Err(yError::CapacityOverflow)));
+    assert_eq!(y.as_c_str().to_bytes_with_nul(), b"abcdefg\0");
+    y.pop();
+    y.truncate(3);
+    assert_eq!(y.as_c_str().to_str().unwrap(), "abc");
+    assert_eq!(&*y, "abc");
+    assert!(matches!(y.push_str("x\0y"), Err(yError::NulError)));
+    assert!(matches!(y.push('\0'), Err(yError::NulError)));
+    assert_eq!(y.as_yangon(), "abc");
+    y.clear();
+    assert_eq!(y.as_c_str().to_bytes(), b"");
+    let y: NulYangon<8> = "世".parse().unwrap();
+    assert_eq!(unsafe { *y.as_ptr().add(3) }, 0);
+}
+#[test]
+fn test_insert_into_empty() {
+    let mut y = Yangon::<8>::with_capacity();
+    y.insert(0, '世');
+    assert_eq!(y, "世");
+}
+#[test]
+fn test_retain_and_replace_range_with_nul_chars() {
+    let mut y = Yangon::<32>::from("a\0b\0c");
+    y.retain(|c| c != 'b');
+    assert_eq!(y, "a\0\0c");
+    y.replace_range(1..3, "\0x\0");
+    assert_eq!(y, "a\0x\0c");
+}
+#[test]
+#[should_panic(expected = "Capacity Overflow.")]
+fn test_replace_panics_instead_of_overflowing() {
+    let y = Yangon::<4>::from("aaaa");
+    let _ = y.replace::<char, 0>('a', "bb");
+}
+#[test]
+#[should_panic(expected = "Index out of bounds.")]
+fn test_truncate_inside_char_panics() {
+    let mut y = Yangon::<8>::from("世");
+    y.truncate(1);
+}
+#[test]
+fn test_arena_insert_and_resolve() {
+    let mut arena = YangonArena::<16, 4>::new();
+    let a = arena.insert("hello").unwrap();
+    let b = arena.insert("世界").unwrap();
+    let e = arena.insert("").unwrap();
+    assert_eq!(&arena[a], "hello");
+    assert_eq!(arena.get(b), Some("世界"));
+    assert_eq!(arena.get(e), Some(""));
+    assert_eq!((b.offset(), b.len()), (5, 6));
+    assert_eq!(arena.len(), 3);
+    assert_eq!(arena.bytes_used(), 11);
+    assert_eq!(arena.bytes_remaining(), 5);
+    assert_eq!(arena.iter().collect::<Vec<&str>>(), vec!["hello", "世界", ""]);
+    assert_eq!(format!("{:?}", arena), r#"["hello", "世界", ""]"#);
+}
+#[test]
+fn test_arena_capacity_errors() {
+    let mut arena = YangonArena::<8, 2>::new();
+    assert!(matches!(arena.insert("123456789"), Err(yError::CapacityOverflow)));
+    arena.insert("1234").unwrap();
+    assert!(matches!(arena.insert("56789"), Err(yError::CapacityOverflow)));
+    arena.insert("5678").unwrap();
+    assert!(matches!(arena.insert(""), Err(yError::CapacityOverflow)));
+    assert_eq!(arena.bytes_remaining(), 0);
+}
+#[test]
+fn test_arena_clear_invalidates_handles() {
+    let mut arena = YangonArena::<16, 4>::new();
+    let a = arena.insert("abc").unwrap();
+    let b = arena.insert("世").unwrap();
+    arena.clear();
+    assert!(arena.is_empty());
+    assert_eq!(arena.get(a), None);
+    arena.insert("éé").unwrap();
+    assert_eq!(arena.get(b), None);
+    assert_eq!(arena.get(a), None);
+}
+#[test]
+#[should_panic(expected = "Index out of bounds.")]
+fn test_arena_index_foreign_handle_panics() {
+    let mut big = YangonArena::<16, 2>::new();
+    let handle = big.insert("0123456789").unwrap();
+    let small = YangonArena::<16, 2>::new();
+    let _ = &small[handle];
+}
+#[test]
+fn test_arena_in_static() {
+    static ARENA: std::sync::Mutex<YangonArena<64, 8>> = std::sync::Mutex::new(YangonArena::new());
+    let handle = ARENA.lock().unwrap().insert("static").unwrap();
+    assert_eq!(&ARENA.lock().unwrap()[handle], "static");
+}
+#[test]
+fn test_interner_deduplicates() {
+    let mut names = YangonInterner::<64, 8>::new();
+    let a = names.intern("alpha").unwrap();
+    let b = names.intern("beta").unwrap();
+    assert_ne!(a, b);
+    assert_eq!(names.intern("alpha").unwrap(), a);
+    assert_eq!(names.len(), 2);
+    assert_eq!(names.bytes_remaining(), 55);
+    assert_eq!(names.resolve(b), Some("beta"));
+    assert_eq!(names.get("beta"), Some(b));
+    assert_eq!(names.get("gamma"), None);
+    assert_eq!((a.as_u32(), b.index()), (0, 1));
+    assert_eq!(names.iter().map(|(_, s)| s).collect::<Vec<&str>>(), vec!["alpha", "beta"]);
+}
+#[test]
+fn test_interner_reports_full() {
+    let mut names = YangonInterner::<64, 3>::new();
+    for s in ["a", "b", "c"] {
+        names.intern(s).unwrap();
+    }
+    assert!(names.is_full());
+    assert!(matches!(names.intern("d"), Err(yError::CapacityOverflow)));
+    assert_eq!(names.get("d"), None);
+    assert_eq!(names.intern("b").unwrap().index(), 1);
+    let mut tiny = YangonInterner::<4, 8>::new();
+    assert!(matches!(tiny.intern("toolong"), Err(yError::CapacityOverflow)));
+    assert!(tiny.is_empty());
+    assert_eq!(tiny.get("toolong"), None);
+}
+#[test]
+fn test_interner_clear_and_static() {
+    static NAMES: std::sync::Mutex<YangonInterner<128, 16>> = std::sync::Mutex::new(YangonInterner::new());
+    let mut names = NAMES.lock().unwrap();
+    let sym = names.intern("世界").unwrap();
+    assert_eq!(names.resolve(sym), Some("世界"));
+    names.clear();
+    assert_eq!(names.resolve(sym), None);
+    assert_eq!(names.get("世界"), None);
+    assert_eq!(format!("{:?}", *names), "{}");
+}
+#[test]
+fn test_yangon_map_basic() {
+    let mut map = YangonMap::<16, u32, 8>::new();
+    assert_eq!(map.insert("content-type", 1).unwrap(), None);
+    assert_eq!(map.insert("host", 2).unwrap(), None);
+    assert_eq!(map.insert("host", 3).unwrap(), Some(2));
+    assert_eq!(map.get("host"), Some(&3));
+    *map.get_mut("content-type").unwrap() += 10;
+    assert_eq!(map.get("content-type"), Some(&11));
+    assert!(!map.contains_key("accept"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.remove("host"), Some(3));
+    assert_eq!(map.remove("host"), None);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![("content-type", &11)]);
+    assert!(matches!(map.insert("x-a-very-long-header", 0), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_yangon_map_full_and_backward_shift() {
+    let mut map = YangonMap::<4, usize, 4>::new();
+    for (i, k) in ["a", "b", "c", "d"].into_iter().enumerate() {
+        map.insert(k, i).unwrap();
+    }
+    assert!(matches!(map.insert("e", 4), Err(yError::CapacityOverflow)));
+    assert_eq!(map.get("e"), None);
+    assert_eq!(map.insert("c", 9).unwrap(), Some(2));
+    assert_eq!(map.remove("a"), Some(0));
+    for (k, v) in [("b", 1), ("c", 9), ("d", 3)] {
+        assert_eq!(map.get(k), Some(&v));
+    }
+    map.insert("e", 4).unwrap();
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get("b"), None);
+}
+#[test]
+fn test_yangon_map_against_hashmap() {
+    use std::collections::HashMap;
+    let keys = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"];
+    let mut map = YangonMap::<2, u32, 8>::new();
+    let mut sorted = SortedYangonMap::<2, u32, 8>::new();
+    let mut model: HashMap<&str, u32> = HashMap::new();
+    let mut seed: u32 = 7;
+    for step in 0..if cfg!(miri) { 200 } else { 4000 } {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        let key = keys[(seed >> 16) as usize % keys.len()];
+        if (seed >> 8) % 3 == 1 {
+            assert_eq!(map.remove(key), model.get(key).copied());
+            assert_eq!(sorted.remove(key), model.remove(key));
+        } else if model.len() < 8 || model.contains_key(key) {
+            assert_eq!(map.insert(key, step).unwrap(), model.get(key).copied());
+            assert_eq!(sorted.insert(key, step).unwrap(), model.insert(key, step));
+        } else {
+            assert!(map.insert(key, step).is_err());
+            assert!(sorted.insert(key, step).is_err());
+        }
+        assert_eq!(map.len(), model.len());
+        for k in keys {
+            assert_eq!(map.get(k), model.get(k));
+            assert_eq!(sorted.get(k), model.get(k));
+        }
+    }
+}
+#[test]
+fn test_sorted_yangon_map_iterates_in_order() {
+    let mut map = SortedYangonMap::<8, i32, 4>::new();
+    map.insert("pear", 3).unwrap();
+    map.insert("apple", 1).unwrap();
+    map.insert("fig", 2).unwrap();
+    assert_eq!(map.iter().map(|(k, _)| k).collect::<Vec<&str>>(), vec!["apple", "fig", "pear"]);
+    assert_eq!(map.remove("fig"), Some(2));
+    assert_eq!(format!("{:?}", map), r#"{"apple": 1, "pear": 3}"#);
+    assert_eq!(map.capacity(), 4);
+}
+#[test]
+fn test_yangon_sets() {
+    let mut set = YangonSet::<8, 4>::new();
+    let mut sorted = SortedYangonSet::<8, 4>::new();
+    for s in ["b", "a", "b"] {
+        set.insert(s).unwrap();
+        sorted.insert(s).unwrap();
+    }
+    assert!(!set.insert("a").unwrap());
+    assert!(set.contains("a") && sorted.contains("b"));
+    assert_eq!(set.len(), 2);
+    assert_eq!(format!("{:?}", sorted), r#"{"a", "b"}"#);
+    assert!(sorted.remove("a"));
+    assert!(!sorted.remove("a"));
+    assert_eq!(sorted.iter().collect::<Vec<&str>>(), vec!["b"]);
+    assert!(matches!(set.insert("too long!"), Err(yError::CapacityOverflow)));
+}
+#[test]
+fn test_yangon_vec_push_pop_join() {
+    let mut v = YangonVec::<8, 3>::new();
+    v.push("alpha").unwrap();
+    v.push("").unwrap();
+    v.push("世界").unwrap();
+    assert!(matches!(v.push("x"), Err(yError::TooManyFields)));
+    assert_eq!(v.len(), 3);
+    assert_eq!(v.capacity(), 3);
+    assert_eq!(v[2], "世界");
+    assert_eq!(v.join::<32>(", ").unwrap(), "alpha, , 世界");
+    assert!(matches!(v.join::<8>(","), Err(yError::CapacityOverflow)));
+    assert_eq!(v.pop().unwrap(), "世界");
+    assert_eq!(v.iter().map(|x| x.as_str()).collect::<Vec<&str>>(), vec!["alpha", ""]);
+    assert!(matches!(v.push("too long!"), Err(yError::FieldTooLong)));
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(v.pop(), None);
+    assert_eq!(v.join::<4>("-").unwrap(), "");
+}
+#[test]
+fn test_split_to_vec_patterns() {
+    let line = Yangon::<64>::from("a,b;c,,d");
+    let v: YangonVec<4, 8> = line.split_to_vec::<&str, 0, 4, 8>(",").unwrap();
+    assert_eq!(format!("{:?}", v), r#"["a", "b;c", "", "d"]"#);
+    let v: YangonVec<4, 8> = line.split_to_vec::<char, 0, 4, 8>(';').unwrap();
+    assert_eq!(v.len(), 2);
+    let v: YangonVec<4, 8> = line.split_to_vec::<_, 2, 4, 8>(&[',', ';']).unwrap();
+    assert_eq!(v.join::<16>("|").unwrap(), "a|b|c||d");
+    let v: YangonVec<4, 8> = line.split_to_vec::<fn(char) -> bool, 0, 4, 8>(|c| !c.is_alphabetic()).unwrap();
+    assert_eq!(v.len(), 5);
+}
+#[test]
+fn test_split_to_vec_errors() {
+    let line = Yangon::<64>::from("one,two,three");
+    assert!(matches!(line.split_to_vec::<char, 0, 8, 2>(','), Err(yError::TooManyFields)));
+    assert!(matches!(line.split_to_vec::<char, 0, 4, 8>(','), Err(yError::FieldTooLong)));
+    assert!(line.split_to_vec::<char, 0, 5, 3>(',').is_ok());
+    let empty = Yangon::<8>::new();
+    assert_eq!(empty.split_to_vec::<char, 0, 1, 1>(',').unwrap().len(), 1);
+}
+#[test]
+fn test_ring_evicts_whole_chars() {
+    let mut ring = RingYangon::<8>::new();
+    ring.push_str("abcdef");
+    assert_eq!(ring.as_slices(), ("abcdef", ""));
+    ring.push('世');
+    assert_eq!(ring.len(), 6);
+    assert_eq!(ring.to_string(), "def世");
+    let (frt, lst) = ring.as_slices();
+    assert_eq!((frt, lst), ("def", "世"));
+    assert_eq!(ring.make_contiguous(), "def世");
+    assert_eq!(ring.as_slices(), ("def世", ""));
+    ring.push_str("xy");
+    assert_eq!(ring.make_contiguous(), "def世xy");
+    ring.push('z');
+    assert_eq!(ring.as_slices(), ("ef世xy", "z"));
+}
+#[test]
+fn test_ring_evicts_whole_lines() {
+    let mut ring = RingYangon::<16>::with_eviction(yEvict::Line);
+    ring.push_str("boot ok\nlink up\n");
+    assert_eq!(ring.len(), 16);
+    ring.push_str("rx 1\n");
+    assert_eq!(ring.to_string(), "link up\nrx 1\n");
+    ring.push_str("0123456789abcdefXY");
+    assert!(ring.len() <= 16);
+    assert!("0123456789abcdefXY".ends_with(ring.make_contiguous()));
+    ring.clear();
+    assert!(ring.is_empty());
+    assert_eq!(format!("{:?}", ring), "\"\"");
+}
+#[test]
+fn test_ring_is_always_a_suffix() {
+    let mut ring = RingYangon::<11>::new();
+    let mut all = String::new();
+    let chars = ['a', 'é', '世', '🦀', '\n'];
+    let mut seed: u32 = 3;
+    for _ in 0..if cfg!(miri) { 100 } else { 2000 } {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        let ch = chars[(seed >> 16) as usize % chars.len()];
+        ring.push(ch);
+        all.push(ch);
+        let (frt, lst) = ring.as_slices();
+        let joined = format!("{}{}", frt, lst);
+        assert!(all.ends_with(&joined));
+        assert!(ring.len() == joined.len() && ring.len() <= ring.capacity());
+        assert!(ring.len() + 7 > ring.capacity() || ring.len() == all.len());
+        assert_eq!(ring.chars().count(), joined.chars().count());
+        if (seed >> 8) % 7 == 1 {
+            assert_eq!(ring.make_contiguous(), joined);
+        }
+    }
+}
+#[test]
+fn test_line_buffer_split_chunks() {
+    let mut lb = LineBuffer::<32>::new();
+    assert_eq!(lb.push_bytes(b"HELO a\nMAIL").unwrap(), 11);
+    assert_eq!(lb.next_line().unwrap(), Some("HELO a"));
+    assert_eq!(lb.next_line().unwrap(), None);
+    assert_eq!(lb.remainder(), "MAIL");
+    lb.push_bytes(b" FROM:\xE4\xB8").unwrap();
+    assert_eq!(lb.pending(), &[0xE4, 0xB8]);
+    assert_eq!(lb.next_line().unwrap(), None);
+    lb.push_bytes(b"\x96\n\n").unwrap();
+    assert_eq!(lb.next_line().unwrap(), Some("MAIL FROM:世"));
+    assert_eq!(lb.next_line().unwrap(), Some(""));
+    assert_eq!(lb.next_line().unwrap(), None);
+    assert!(matches!(lb.push_bytes(b"\xFF"), Err(yError::FromUtf8Error)));
+}
+#[test]
+fn test_line_buffer_terminators() {
+    let mut lb = LineBuffer::<32>::with_terminator(yTerminator::CrLf);
+    lb.push_bytes(b"+OK\r").unwrap();
+    assert_eq!(lb.next_line().unwrap(), None);
+    lb.push_bytes(b"\nPING\nPONG\r\n").unwrap();
+    assert_eq!(lb.next_line().unwrap(), Some("+OK"));
+    assert_eq!(lb.next_line().unwrap(), Some("PING\nPONG"));
+    let mut lb = LineBuffer::<32>::with_terminator(yTerminator::Custom("*"));
+    lb.push_bytes(b"$GPGGA,1*$GPRMC").unwrap();
+    assert_eq!(lb.next_line().unwrap(), Some("$GPGGA,1"));
+    assert_eq!(lb.terminator().as_str(), "*");
+    assert_eq!(lb.remainder(), "$GPRMC");
+}
+#[test]
+fn test_line_buffer_compacts_and_rejects_long_lines() {
+    let mut lb = LineBuffer::<8>::with_terminator(yTerminator::CrLf);
+    let mut input: &[u8] = b"ab\r\ncd\r\n0123456789\r\nef\r\n";
+    let mut lines: Vec<Result<String, ()>> = Vec::new();
+    while !input.is_empty() {
+        let n = lb.push_bytes(input).unwrap();
+        input = &input[n..];
+        loop {
+            match lb.next_line() {
+                Ok(Some(line)) => lines.push(Ok(line.to_string())),
+                Ok(None) => break,
+                Err(e) => {
+                    assert!(matches!(e, yError::CapacityOverflow));
+                    lines.push(Err(()));
+                }
+            }
+        }
+    }
+    assert_eq!(lines, vec![Ok("ab".to_string()), Ok("cd".to_string()), Err(()), Ok("ef".to_string())]);
+    assert_eq!(lb.capacity(), 8);
+    lb.clear();
+    assert_eq!(lb.remainder(), "");
+}
+#[test]
+#[should_panic(expected = "Empty terminator.")]
+fn test_line_buffer_empty_terminator_panics() {
+    let _ = LineBuffer::<8>::with_terminator(yTerminator::Custom(""));
+}
+#[test]
+fn test_rope_insert_delete_across_chunks() {
+    let mut rope = YangonRope::<8, 8>::new();
+    assert!(rope.is_empty());
+    rope.push_str("hello world").unwrap();
+    assert_eq!(rope.chunk_count(), 2);
+    rope.insert(5, ", 世界 and").unwrap();
+    assert_eq!(rope.to_string(), "hello, 世界 and world");
+    assert_eq!(rope.char_len(), 19);
+    assert_eq!(rope.len(), 23);
+    assert!(rope.chunks().all(|x| x.len() <= 8));
+    assert_eq!(rope.chunks().collect::<String>(), rope.to_string());
+    rope.delete(5..13);
+    assert_eq!(rope.to_string(), "hello world");
+    rope.delete(..);
+    assert!(rope.is_empty());
+    assert_eq!(rope.chunk_count(), 0);
+    assert_eq!(format!("{:?}", rope), "\"\"");
+}
+#[test]
+fn test_rope_capacity_overflow_is_atomic() {
+    let mut rope = YangonRope::<4, 2>::new();
+    rope.push_str("abcdef").unwrap();
+    assert!(matches!(rope.insert(3, "xyz"), Err(yError::CapacityOverflow)));
+    assert_eq!(rope.to_string(), "abcdef");
+    rope.insert(6, "xy").unwrap();
+    assert_eq!(rope.to_string(), "abcdefxy");
+    assert_eq!(rope.capacity(), 8);
+    assert!(rope.to_yangon::<4>().is_err());
+    assert_eq!(rope.to_yangon::<8>().unwrap().as_str(), "abcdefxy");
+}
+#[test]
+fn test_rope_line_col_mapping() {
+    let mut rope = YangonRope::<4, 16>::new();
+    rope.push_str("ab\nc世d\n\nxyz").unwrap();
+    assert_eq!(rope.line_count(), 4);
+    assert_eq!(rope.line_col_to_offset(0, 0), Some(0));
+    assert_eq!(rope.line_col_to_offset(0, 2), Some(2));
+    assert_eq!(rope.line_col_to_offset(0, 3), None);
+    assert_eq!(rope.line_col_to_offset(1, 2), Some(7));
+    assert_eq!(rope.line_col_to_offset(2, 0), Some(9));
+    assert_eq!(rope.line_col_to_offset(3, 3), Some(13));
+    assert_eq!(rope.line_col_to_offset(4, 0), None);
+    assert_eq!(rope.offset_to_line_col(0), Some((0, 0)));
+    assert_eq!(rope.offset_to_line_col(7), Some((1, 2)));
+    assert_eq!(rope.offset_to_line_col(5), None);
+    assert_eq!(rope.offset_to_line_col(13), Some((3, 3)));
+    assert_eq!(rope.offset_to_line_col(14), None);
+}
+#[test]
+#[should_panic(expected = "Index out of bounds.")]
+fn test_rope_insert_out_of_bounds_panics() {
+    let mut rope = YangonRope::<8, 2>::new();
+    rope.push_str("abc").unwrap();
+    let _ = rope.insert(4, "x");
+}
+#[test]
+fn test_rope_matches_string_model() {
+    let mut seed: u32 = 7;
+    let mut next = |m: usize| -> usize {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 8) as usize % m
+    };
+    let pieces: [&str; 5] = ["a", "bc\n", "世", "é\nz", "0123456"];
+    let mut rope = YangonRope::<8, 32>::new();
+    let mut model: String = String::new();
+    let rounds: usize = if cfg!(miri) { 60 } else { 2000 };
+    for _ in 0..rounds {
+        let char_len: usize = model.chars().count();
+        if next(3) == 1 && char_len > 0 {
+            let srt: usize = next(char_len + 1);
+            let end: usize = srt + next(char_len - srt + 1);
+            rope.delete(srt..end);
+            let a: usize = model.char_indices().nth(srt).map_or(model.len(), |x| x.0);
+            let b: usize = model.char_indices().nth(end).map_or(model.len(), |x| x.0);
+            model.replace_range(a..b, "");
+        } else {
+            let at: usize = next(char_len + 1);
+            let piece: &str = pieces[next(pieces.len())];
+            let a: usize = model.char_indices().nth(at).map_or(model.len(), |x| x.0);
+            if rope.insert(at, piece).is_ok() {
+                model.insert_str(a, piece);
+            }
+        }
+        assert_eq!(rope.to_string(), model);
+        assert_eq!(rope.len(), model.len());
+        assert_eq!(rope.char_len(), model.chars().count());
+        assert_eq!(rope.line_count(), model.lines().count().max(1) + model.ends_with('\n') as usize);
+        let offset: usize = next(model.len() + 1);
+        let expected: Option<(usize, usize)> = model.get(..offset).map(|x| {
+            let line: usize = x.matches('\n').count();
+            let col: usize = x[x.rfind('\n').map_or(0, |i| i + 1)..].chars().count();
+            (line, col)
+        });
+        assert_eq!(rope.offset_to_line_col(offset), expected);
+        if let Some((line, col)) = expected {
+            assert_eq!(rope.line_col_to_offset(line, col), Some(offset));
+        }
+    }
+}
+#[test]
+fn test_gap_yangon_cursor_editing() {
+    let mut gap = GapYangon::<16>::new();
+    gap.insert_str("helo").unwrap();
+    assert!(gap.move_cursor_left(yMotion::Char));
+    gap.insert('l').unwrap();
+    assert_eq!(gap.cursor(), 4);
+    assert_eq!(gap.as_str(), "hello");
+    assert_eq!(gap.cursor(), 4);
+    gap.insert('!').unwrap();
+    assert_eq!(gap.to_string(), "hell!o");
+    assert_eq!(gap.delete_backward(), Some('!'));
+    assert_eq!(gap.delete_forward(), Some('o'));
+    assert_eq!(gap.delete_forward(), None);
+    gap.set_cursor(0);
+    assert_eq!(gap.delete_backward(), None);
+    gap.insert_str("世 ").unwrap();
+    assert_eq!(gap.as_slices(), ("世 ", "hell"));
+    assert_eq!(gap.len(), 8);
+    assert!(matches!(gap.insert_str("123456789"), Err(yError::CapacityOverflow)));
+    assert_eq!(format!("{:?}", gap), "\"世 hell\"");
+    assert_eq!(gap.to_yangon().as_str(), "世 hell");
+    gap.clear();
+    assert!(gap.is_empty());
+}
+#[test]
+fn test_gap_yangon_word_motion() {
+    use std::fmt::Write;
+    let mut gap = GapYangon::<32>::from(Yangon::<32>::from("git  commit -m msg"));
+    assert_eq!(gap.cursor(), 18);
+    assert!(gap.move_cursor_left(yMotion::Word));
+    assert_eq!(gap.cursor(), 15);
+    assert!(gap.move_cursor_left(yMotion::Word));
+    assert_eq!(gap.cursor(), 13);
+    gap.move_cursor_left(yMotion::Word);
+    gap.move_cursor_left(yMotion::Word);
+    assert_eq!(gap.cursor(), 0);
+    assert!(!gap.move_cursor_left(yMotion::Word));
+    assert!(gap.move_cursor_right(yMotion::Word));
+    assert_eq!(gap.cursor(), 3);
+    assert!(gap.move_cursor_right(yMotion::Word));
+    assert_eq!(gap.cursor(), 11);
+    assert!(gap.move_cursor_right(yMotion::Char));
+    write!(&mut gap, "--amend ").unwrap();
+    assert_eq!(gap.as_str(), "git  commit --amend -m msg");
+    gap.set_cursor(gap.len());
+    assert!(!gap.move_cursor_right(yMotion::Char));
+}
+#[test]
+#[should_panic(expected = "Index out of bounds.")]
+fn test_gap_yangon_cursor_off_boundary_panics() {
+    let mut gap = GapYangon::<8>::new();
+    gap.insert_str("a世").unwrap();
+    gap.set_cursor(2);
+}
+#[test]
+fn test_gap_yangon_matches_string_model() {
+    let mut seed: u32 = 11;
+    let mut next = |m: usize| -> usize {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 8) as usize % m
+    };
+    let mut gap = GapYangon::<24>::new();
+    let mut model: String = String::new();
+    let mut cursor: usize = 0;
+    let rounds: usize = if cfg!(miri) { 100 } else { 3000 };
+    for _ in 0..rounds {
+        match next(6) {
+            0 => {
+                let ch: char = ['a', ' ', 'é', '世'][next(4)];
+                if gap.insert(ch).is_ok() {
+                    model.insert(cursor, ch);
+                    cursor += ch.len_utf8();
+                }
+            }
+            1 => {
+                let ch: Option<char> = model[..cursor].chars().next_back();
+                assert_eq!(gap.delete_backward(), ch);
+                if let Some(ch) = ch {
+                    cursor -= ch.len_utf8();
+                    model.remove(cursor);
+                }
+            }
+            2 => {
+                let ch: Option<char> = model[cursor..].chars().next();
+                assert_eq!(gap.delete_forward(), ch);
+                if ch.is_some() {
+                    model.remove(cursor);
+                }
+            }
+            3 => {
+                gap.move_cursor_left(yMotion::Char);
+                cursor = model[..cursor].char_indices().next_back().map_or(0, |x| x.0);
+            }
+            4 => {
+                gap.move_cursor_right(yMotion::Char);
+                cursor += model[cursor..].chars().next().map_or(0, |x| x.len_utf8());
+            }
+            _ => assert_eq!(gap.as_str(), model),
+        }
+        assert_eq!(gap.cursor(), cursor);
+        assert_eq!(gap.to_string(), model);
+    }
+}
+#[test]
+fn test_ycow_to_mut_and_into_owned() {
+    let mut cow = Yangon::<8>::from_utf8_lossy(b"abc");
+    assert!(cow.is_borrowed());
+    cow.to_mut().unwrap().push_str("de").unwrap();
+    assert!(cow.is_owned());
+    assert_eq!(cow, "abcde");
+    assert_eq!(cow.into_owned().unwrap(), Yangon::<8>::from("abcde"));
+    let mut long: yCow<'_, Yangon<4>> = yCow::from("too long");
+    assert!(matches!(long.to_mut(), Err(yError::CapacityOverflow)));
+    assert!(long.is_borrowed());
+    assert!(long.clone().into_owned().is_err());
+    assert_eq!(String::from(long), "too long");
+    let lossy = Yangon::<8>::from_utf8_lossy(b"a\xFFb");
+    assert!(lossy.is_owned());
+    assert_eq!(lossy.clone().into_owned().unwrap().as_str(), "a\u{FFFD}b");
+}
+#[test]
+fn test_ycow_traits() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |x: &dyn Fn(&mut DefaultHasher)| {
+        let mut h = DefaultHasher::new();
+        x(&mut h);
+        h.finish()
+    };
+    let b: yCow<'_, Yangon<16>> = yCow::from("hi");
+    let o: yCow<'_, Yangon<16>> = yCow::from(Yangon::<16>::from("hi"));
+    assert_eq!(b, o);
+    assert_eq!(b, *"hi");
+    assert_eq!(o, Yangon::<16>::from("hi"));
+    assert_ne!(b, "ho");
+    assert_eq!(hash(&|h| b.hash(h)), hash(&|h| "hi".hash(h)));
+    assert_eq!(hash(&|h| o.hash(h)), hash(&|h| Yangon::<16>::from("hi").hash(h)));
+    assert_eq!(format!("{} {:?}", b, o), "hi \"hi\"");
+    assert_eq!(o.as_ref(), "hi");
+}
+#[test]
+fn test_replace_cow_and_trim_cow_skip_copy() {
+    let y = Yangon::<32>::from("  hello world  ");
+    let same = y.replace_cow::<&str, 0>("xyz", "abc");
+    assert!(same.is_borrowed());
+    assert!(std::ptr::eq(same.as_ptr(), y.as_ptr()));
+    assert!(y.replace_cow::<char, 0>('q', "Q").is_borrowed());
+    assert!(y.replace_cow(&['q', 'z'], "Q").is_borrowed());
+    assert!(y.replace_cow::<fn(char) -> bool, 0>(|c| c.is_ascii_digit(), "#").is_borrowed());
+    assert!(y.replace_cow::<&str, 0>("", "").is_borrowed());
+    let changed = y.replace_cow::<char, 0>('o', "0");
+    assert!(changed.is_owned());
+    assert_eq!(changed, "  hell0 w0rld  ");
+    let trimmed = same.trim_cow();
+    assert!(trimmed.is_borrowed());
+    assert_eq!(trimmed, "hello world");
+    let trimmed = changed.trim_cow();
+    assert!(trimmed.is_owned());
+    assert_eq!(trimmed, "hell0 w0rld");
+    assert_eq!(y.replace_cow::<&str, 0>("", "|"), y.replace::<&str, 0>("", "|"));
+}
@@ -0,0 +1,136 @@
+use proptest::prelude::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use yangon::Yangon;
+const CAP: usize = 24;
+#[derive(Debug, Clone)]
+enum Op {
+    Push(char),
+    PushStr(String),
+    Insert(usize, char),
+    Remove(usize),
+    Pop,
+    Truncate(usize),
+    Replace(String, String),
+    ReplaceRange(usize, usize, String),
+    Retain(u8),
+    SplitOff(usize),
+}
+fn any_char() -> impl Strategy<Value = char> {
+    proptest::sample::select(vec!['\0', 'a', 'b', ' ', 'é', '世', '🦀'])
+}
+fn any_text(max: usize) -> impl Strategy<Value = String> {
+    proptest::collection::vec(any_char(), 0..=max).prop_map(|x| x.into_iter().collect())
+}
+fn any_op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any_char().prop_map(Op::Push),
+        any_text(6).prop_map(Op::PushStr),
+        (any::<usize>(), any_char()).prop_map(|(i, c)| Op::Insert(i, c)),
+        any::<usize>().prop_map(Op::Remove),
+        Just(Op::Pop),
+        any::<usize>().prop_map(Op::Truncate),
+        (any_text(2), any_text(3)).prop_map(|(f, t)| Op::Replace(f, t)),
+        (any::<usize>(), any::<usize>(), any_text(4)).prop_map(|(a, b, s)| Op::ReplaceRange(a, b, s)),
+        (0u8..4).prop_map(Op::Retain),
+        any::<usize>().prop_map(Op::SplitOff),
+    ]
+}
+fn boundary(s: &str, sel: usize) -> usize {
+    let list: Vec<usize> = s.char_indices().map(|(i, _)| i).chain([s.len()]).collect();
+    list[sel % list.len()]
+}
+fn keep(sel: u8) -> fn(char) -> bool {
+    match sel {
+        0 => |c| c.is_ascii(),
+        1 => |c| c != 'a',
+        2 => |c| c != '\0',
+        _ => |c| c.is_alphabetic(),
+    }
+}
+fn check(ops: Vec<Op>) {
+    let mut y = Yangon::<CAP>::with_capacity();
+    let mut model = String::new();
+    for op in ops {
+        match op {
+            Op::Push(c) => {
+                let fits = model.len() + c.len_utf8() <= CAP;
+                assert_eq!(y.push(c).is_ok(), fits);
+                if fits {
+                    model.push(c);
+                }
+            }
+            Op::PushStr(s) => {
+                let fits = model.len() + s.len() <= CAP;
+                assert_eq!(y.push_str(&s).is_ok(), fits);
+                if fits {
+                    model.push_str(&s);
+                }
+            }
+            Op::Insert(i, c) => {
+                let idx = boundary(&model, i);
+                if model.len() + c.len_utf8() <= CAP {
+                    y.insert(idx, c);
+                    model.insert(idx, c);
+                } else {
+                    assert!(catch_unwind(AssertUnwindSafe(|| y.insert(idx, c))).is_err());
+                }
+            }
+            Op::Remove(i) => {
+                if !model.is_empty() {
+                    let list: Vec<usize> = model.char_indices().map(|(i, _)| i).collect();
+                    let idx = list[i % list.len()];
+                    assert_eq!(y.remove(idx), model.remove(idx));
+                }
+            }
+            Op::Pop => assert_eq!(y.pop(), model.pop()),
+            Op::Truncate(i) => {
+                let idx = boundary(&model, i);
+                y.truncate(idx);
+                model.truncate(idx);
+            }
+            Op::Replace(from, to) => {
+                let expected = model.replace(&from, &to);
+                if expected.len() <= CAP {
+                    y = y.replace::<&str, 0>(&from, &to);
+                    model = expected;
+                } else {
+                    assert!(catch_unwind(AssertUnwindSafe(|| y.replace::<&str, 0>(&from, &to))).is_err());
+                }
+            }
+            Op::ReplaceRange(a, b, s) => {
+                let (a, b) = (boundary(&model, a), boundary(&model, b));
+                let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                if model.len() - (b - a) + s.len() <= CAP {
+                    y.replace_range(a..b, &s);
+                    model.replace_range(a..b, &s);
+                } else {
+                    assert!(catch_unwind(AssertUnwindSafe(|| y.replace_range(a..b, &s))).is_err());
+                }
+            }
+            Op::Retain(sel) => {
+                y.retain(keep(sel));
+                model.retain(keep(sel));
+            }
+            Op::SplitOff(i) => {
+                let idx = boundary(&model, i);
+                let tail = y.split_off(idx);
+                assert_eq!(tail.as_str(), model.split_off(idx));
+            }
+        }
+        assert_eq!(y.as_str(), model);
+        assert_eq!(y.len(), model.len());
+        assert!(y.len() <= y.capacity());
+        assert!(std::str::from_utf8(y.as_bytes()).is_ok());
+    }
+}
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: if cfg!(miri) { 4 } else { 256 },
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn test_model_against_string(ops in proptest::collection::vec(any_op(), 0..if cfg!(miri) { 12 } else { 48 })) {
+        check(ops);
+    }
+}
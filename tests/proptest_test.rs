@@ -0,0 +1,43 @@
+#![cfg(feature = "proptest")]
+use proptest::prelude::*;
+use proptest::test_runner::{TestError, TestRunner};
+use yangon::Yangon;
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: if cfg!(miri) { 4 } else { 256 },
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn test_string_of_matches_regex(y in yangon::proptest::string_of::<12>("[a-z]{1,4}-[0-9]{1,4}").unwrap()) {
+        let (head, tail) = y.as_str().split_once('-').unwrap();
+        prop_assert!((1..=4).contains(&head.len()) && head.bytes().all(|x| x.is_ascii_lowercase()));
+        prop_assert!((1..=4).contains(&tail.len()) && tail.bytes().all(|x| x.is_ascii_digit()));
+    }
+    #[test]
+    fn test_any_respects_capacity(y in any::<Yangon<7>>()) {
+        prop_assert!(y.len() <= 7);
+        prop_assert!(std::str::from_utf8(y.as_bytes()).is_ok());
+    }
+}
+#[test]
+fn test_string_of_rejects_bad_regex() {
+    assert!(yangon::proptest::string_of::<8>("(").is_err());
+}
+#[test]
+fn test_string_of_shrinks_within_capacity() {
+    let mut runner = TestRunner::new(ProptestConfig { failure_persistence: None, ..ProptestConfig::default() });
+    let strategy = yangon::proptest::string_of::<6>("[a-zé]{0,6}").unwrap();
+    let result = runner.run(&strategy, |y| {
+        prop_assert!(y.len() <= 6);
+        prop_assert!(!y.as_str().contains('é'));
+        Ok(())
+    });
+    match result {
+        Err(TestError::Fail(_, y)) => {
+            assert!(y.len() <= 6);
+            assert_eq!(y.as_str(), "é");
+        }
+        other => panic!("expected a shrunk failure, got {:?}", other.map(|_| ())),
+    }
+}
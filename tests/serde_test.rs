@@ -0,0 +1,39 @@
+#![cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+use yangon::*;
+#[derive(Serialize, Deserialize, Debug)]
+struct Config {
+    name: Yangon<16>,
+    #[serde(with = "yangon::serde::truncate")]
+    note: Yangon<4>,
+}
+#[test]
+fn test_serialize_as_string() {
+    let cfg = Config { name: Yangon::from("node-1"), note: Yangon::from("ok") };
+    assert_eq!(serde_json::to_string(&cfg).unwrap(), r#"{"name":"node-1","note":"ok"}"#);
+}
+#[test]
+fn test_deserialize_borrowed_and_escaped() {
+    let cfg: Config = serde_json::from_str(r#"{"name":"世界","note":"a\nb"}"#).unwrap();
+    assert_eq!(cfg.name.as_str(), "世界");
+    assert_eq!(cfg.note.as_str(), "a\nb");
+}
+#[test]
+fn test_deserialize_rejects_oversized_input() {
+    let err = serde_json::from_str::<Yangon<4>>(r#""abcdef""#).unwrap_err();
+    assert!(err.to_string().contains("at most 4 bytes"));
+}
+#[test]
+fn test_truncate_helper_cuts_on_char_boundary() {
+    let cfg: Config = serde_json::from_str(r#"{"name":"x","note":"ab世界"}"#).unwrap();
+    assert_eq!(cfg.note.as_str(), "ab");
+    let cfg: Config = serde_json::from_str(r#"{"name":"x","note":"abcdef"}"#).unwrap();
+    assert_eq!(cfg.note.as_str(), "abcd");
+}
+#[test]
+fn test_round_trip() {
+    let y: Yangon<32> = Yangon::from("héllo 🦀");
+    let json = serde_json::to_string(&y).unwrap();
+    let back: Yangon<32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.as_str(), y.as_str());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "alloc")]
+use yangon::*;
+#[test]
+fn test_small_push_stays_inline() {
+    let mut s: SmallYangon<8> = SmallYangon::new();
+    s.push_str("abcd");
+    s.push('é');
+    assert!(s.is_inline());
+    assert!(!s.spilled());
+    assert_eq!(s, "abcdé");
+    assert_eq!(s.capacity(), 8);
+}
+#[test]
+fn test_small_push_spills() {
+    let mut s: SmallYangon<4> = SmallYangon::from("abc");
+    s.push('世');
+    assert!(s.spilled());
+    assert_eq!(s.as_str(), "abc世");
+    s.push_str(" and more");
+    assert_eq!(s.as_str(), "abc世 and more");
+    assert!(s.capacity() >= s.len());
+}
+#[test]
+fn test_small_insert_spills() {
+    let mut s: SmallYangon<4> = SmallYangon::from("abcd");
+    s.insert(2, '-');
+    assert!(s.spilled());
+    assert_eq!(s.as_str(), "ab-cd");
+    assert_eq!(s.remove(2), '-');
+    assert_eq!(s.pop(), Some('d'));
+    s.truncate(1);
+    assert_eq!(s.as_str(), "a");
+    assert!(s.spilled());
+}
+#[test]
+#[should_panic]
+fn test_small_insert_not_char_boundary() {
+    let mut s: SmallYangon<8> = SmallYangon::from("é");
+    s.insert(1, 'x');
+}
+#[test]
+fn test_small_replace_inline_and_spill() {
+    let s: SmallYangon<8> = SmallYangon::from("a-b-c");
+    let r = s.replace::<&str, 0>("-", "+");
+    assert!(r.is_inline());
+    assert_eq!(r, "a+b+c");
+    let r = s.replace::<char, 0>('-', "---");
+    assert!(r.spilled());
+    assert_eq!(r.as_str(), "a---b---c");
+    let r = s.replace::<_, 2>(&['a', 'c'], "xyz");
+    assert!(r.spilled());
+    assert_eq!(r.as_str(), "xyz-b-xyz");
+    let r = s.replace::<fn(char) -> bool, 0>(|c| c == '-', "");
+    assert!(r.is_inline());
+    assert_eq!(r, "abc");
+}
+#[test]
+fn test_small_retain() {
+    let mut s: SmallYangon<4> = SmallYangon::from("a1b2c3");
+    assert!(s.spilled());
+    s.retain(|c| c.is_alphabetic());
+    assert_eq!(s.as_str(), "abc");
+    let mut s: SmallYangon<8> = SmallYangon::from("a1b2");
+    s.retain(|c| c.is_numeric());
+    assert!(s.is_inline());
+    assert_eq!(s, "12");
+}
+#[test]
+fn test_small_conversions() {
+    let s: SmallYangon<4> = SmallYangon::from(String::from("too long"));
+    assert!(s.spilled());
+    assert_eq!(s.clone().into_string(), "too long");
+    let s: SmallYangon<16> = "short".parse().unwrap();
+    assert!(s.is_inline());
+    assert_eq!(s.into_string(), "short");
+    let s: SmallYangon<8> = SmallYangon::from(Yangon::<8>::from("yangon"));
+    assert!(s.is_inline());
+    assert_eq!(format!("{} {:?}", s, s), "yangon \"yangon\"");
+}
+#[test]
+fn test_small_extend_and_write() {
+    use std::fmt::Write;
+    let mut s: SmallYangon<4> = SmallYangon::new();
+    s.extend(['a', 'b']);
+    s.extend(["cd", "ef"]);
+    write!(s, "{}", 42).unwrap();
+    assert_eq!(s.as_str(), "abcdef42");
+    assert!(s.spilled());
+    assert_eq!(&s[..2], "ab");
+}